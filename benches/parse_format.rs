@@ -0,0 +1,67 @@
+//! Benchmarks for parsing and formatting durations, run with `cargo bench`.
+//!
+//! This uses a small timing loop rather than criterion so that it builds with
+//! no extra dependencies; each benchmark reports the mean time per iteration.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use serde_duration::{format_duration, format_duration_compound, parse_duration};
+
+const ITERATIONS: u32 = 200_000;
+
+fn bench<F: FnMut()>(group: &str, name: &str, mut f: F) {
+    // Warm up caches and branch predictors before timing
+    for _ in 0..ITERATIONS / 10 {
+        f();
+    }
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    let per_iteration = start.elapsed() / ITERATIONS;
+    let label = format!("{}/{}", group, name);
+    println!("{:<32} {:>10?}", label, per_iteration);
+}
+
+fn bench_parse() {
+    let inputs = [
+        ("short", "30s"),
+        ("fractional", "1.5h"),
+        ("compound", "1h30m15s"),
+        ("spelled_out", "10 seconds"),
+        (
+            "long_compound",
+            "1w 2d 3h 4m 5s 6ms 7us 8ns 1w 2d 3h 4m 5s 6ms 7us 8ns",
+        ),
+    ];
+    for (name, input) in inputs {
+        bench("parse", name, || {
+            let _ = black_box(parse_duration(black_box(input)));
+        });
+    }
+}
+
+fn bench_format() {
+    let durations = [
+        ("zero", Duration::ZERO),
+        ("seconds", Duration::from_secs(90)),
+        ("hours", Duration::from_secs(7200)),
+        ("nanos", Duration::new(5400, 1)),
+    ];
+    for (name, duration) in durations {
+        bench("format", name, || {
+            black_box(format_duration(black_box(&duration)));
+        });
+    }
+    for (name, duration) in durations {
+        bench("format_compound", name, || {
+            black_box(format_duration_compound(black_box(&duration)));
+        });
+    }
+}
+
+fn main() {
+    bench_parse();
+    bench_format();
+}
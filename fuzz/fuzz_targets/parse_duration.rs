@@ -0,0 +1,55 @@
+//! Feeds arbitrary strings to every parser, which must return `Ok` or `Err`
+//! but never panic. Anything that parses must also survive formatting and
+//! parsing again unchanged.
+//!
+//! Run it from the repository root with a nightly toolchain and
+//! [cargo-fuzz](https://github.com/rust-fuzz/cargo-fuzz):
+//!
+//! ```sh
+//! cargo install cargo-fuzz
+//! cargo +nightly fuzz run parse_duration
+//! ```
+//!
+//! Add `-- -max_total_time=60` to stop after a minute. Crashing inputs are
+//! saved under `fuzz/artifacts/parse_duration/`.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use serde_duration::{
+    format_duration, format_duration_compound, parse_duration, parse_duration_saturating,
+    parse_duration_with_default, parse_duration_with_options, try_parse_duration, ParseOptions,
+    Unit,
+};
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(s) = std::str::from_utf8(data) else {
+        return;
+    };
+
+    if let Ok(duration) = parse_duration(s) {
+        assert_eq!(parse_duration(&format_duration(&duration)).unwrap(), duration);
+        assert_eq!(
+            parse_duration(&format_duration_compound(&duration)).unwrap(),
+            duration
+        );
+    }
+    let _ = try_parse_duration(s);
+    let _ = parse_duration_saturating(s);
+    let _ = parse_duration_with_default(s, Unit::Millis);
+    let options = ParseOptions::new()
+        .strict(true)
+        .decimal_comma(true)
+        .scientific(true);
+    let _ = parse_duration_with_options(s, &options);
+    let _ = s.parse::<Unit>();
+
+    // The serde modules with their own grammars
+    let json = serde_json::to_string(s).unwrap();
+    let mut deserializer = serde_json::Deserializer::from_str(&json);
+    let _ = serde_duration::go::deserialize(&mut deserializer);
+    let mut deserializer = serde_json::Deserializer::from_str(&json);
+    let _ = serde_duration::iso8601::deserialize(&mut deserializer);
+    let mut deserializer = serde_json::Deserializer::from_str(&json);
+    let _ = serde_duration::clock::deserialize(&mut deserializer);
+});
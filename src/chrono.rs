@@ -0,0 +1,116 @@
+//! Serialization of `chrono::Duration` values.
+//!
+//! Uses the same format as the crate root, except that a leading '-' marks a
+//! negative duration, e.g. "-30s" or "-1h30m". Requires the `chrono` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Offset {
+//!     #[serde(with = "serde_duration::chrono")]
+//!     skew: chrono::Duration,
+//! }
+//!
+//! let offset: Offset = serde_json::from_str(r#"{"skew": "-1m30s"}"#).unwrap();
+//! assert_eq!(offset.skew, chrono::Duration::seconds(-90));
+//! ```
+
+use core::fmt;
+use serde::{de, ser, Deserializer, Serializer};
+
+use crate::{format_signed, parse_signed, visit_whole_number};
+
+/// Serializes a `chrono::Duration` to a string, prefixed with '-' if it is
+/// negative.
+///
+/// # Arguments
+///
+/// * `duration` - The duration to serialize
+/// * `serializer` - The serde serializer
+///
+/// # Returns
+///
+/// A result containing the serialized string if serialization was successful,
+/// or an error if serialization failed.
+pub fn serialize<S>(duration: &::chrono::Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let magnitude = duration.abs().to_std().map_err(ser::Error::custom)?;
+    serializer.serialize_str(&format_signed(
+        *duration < ::chrono::Duration::zero(),
+        &magnitude,
+    ))
+}
+
+/// Deserializes a `chrono::Duration` from a string that may start with '-',
+/// or from a signed number of seconds.
+///
+/// # Arguments
+///
+/// * `deserializer` - The serde deserializer
+///
+/// # Returns
+///
+/// A result containing the deserialized duration if deserialization was successful,
+/// or an error if deserialization failed.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<::chrono::Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(ChronoVisitor)
+    } else {
+        deserializer.deserialize_str(ChronoVisitor)
+    }
+}
+
+struct ChronoVisitor;
+
+impl<'de> de::Visitor<'de> for ChronoVisitor {
+    type Value = ::chrono::Duration;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a duration string such as \"-30s\" or a number of seconds")
+    }
+
+    fn visit_str<E>(self, s: &str) -> Result<::chrono::Duration, E>
+    where
+        E: de::Error,
+    {
+        let (negative, magnitude) = parse_signed(s).map_err(E::custom)?;
+        let duration = ::chrono::Duration::from_std(magnitude)
+            .map_err(|_| E::custom("duration is out of range for chrono::Duration"))?;
+        Ok(if negative { -duration } else { duration })
+    }
+
+    fn visit_i64<E>(self, secs: i64) -> Result<::chrono::Duration, E>
+    where
+        E: de::Error,
+    {
+        // `chrono::Duration::seconds` panics when out of range, milliseconds
+        // can't be.
+        secs.checked_mul(1000)
+            .map(::chrono::Duration::milliseconds)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Signed(secs), &self))
+    }
+
+    fn visit_u64<E>(self, secs: u64) -> Result<::chrono::Duration, E>
+    where
+        E: de::Error,
+    {
+        let secs = i64::try_from(secs)
+            .map_err(|_| E::invalid_value(de::Unexpected::Unsigned(secs), &self))?;
+        self.visit_i64(secs)
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<::chrono::Duration, A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        visit_whole_number(self, map)
+    }
+}
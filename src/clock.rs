@@ -0,0 +1,186 @@
+//! Serialization of `Duration` values as clock times such as "01:30:00".
+//!
+//! Durations are written as zero-padded "HH:MM:SS", with a decimal fraction
+//! of a second only when there is one, e.g. "00:00:01.5". Hours are not
+//! limited to 24, so 100 hours are "100:00:00". Use [`format_clock`] to pick
+//! a different hour width or to leave out zero hours.
+//!
+//! When parsing, both "HH:MM:SS" and "MM:SS" are accepted. Minutes and
+//! seconds that follow another field must be between 0 and 59. A two-field
+//! string is always read as "MM:SS", never as "HH:MM", and its leading
+//! minutes are not limited to 59, so "90:00" is 90 minutes and serializes
+//! back as "01:30:00".
+//!
+//! # Examples
+//!
+//! ```
+//! use std::time::Duration;
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct MyConfig {
+//!     #[serde(with = "serde_duration::clock")]
+//!     length: Duration,
+//! }
+//!
+//! let config: MyConfig = serde_json::from_str(r#"{"length": "01:30:00"}"#).unwrap();
+//! assert_eq!(config.length, Duration::from_secs(5400));
+//! assert_eq!(serde_json::to_string(&config).unwrap(), r#"{"length":"01:30:00"}"#);
+//! ```
+
+use alloc::format;
+use alloc::string::String;
+use core::time::Duration;
+use serde::{Deserializer, Serializer};
+
+use crate::{parse_value, trim, DurationParseError, InvalidDurationError, StrVisitor, Unit};
+
+/// Serializes a duration as a clock time such as "01:30:00".
+///
+/// # Arguments
+///
+/// * `duration` - The duration to serialize
+/// * `serializer` - The serde serializer
+///
+/// # Returns
+///
+/// A result containing the serialized string if serialization was successful,
+/// or an error if serialization failed.
+pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&format_clock(duration, 2, true))
+}
+
+/// Deserializes a duration from a clock time such as "01:30:00" or "90:00".
+///
+/// # Arguments
+///
+/// * `deserializer` - The serde deserializer
+///
+/// # Returns
+///
+/// A result containing the deserialized duration if deserialization was successful,
+/// or an error if deserialization failed.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_str(StrVisitor {
+        expecting: "a clock time such as \"01:30:00\"",
+        parse: parse_clock,
+    })
+}
+
+/// Formats a duration as a clock time, with control over the hour field.
+///
+/// Minutes and seconds are always zero-padded to two digits, while the hours
+/// are padded to `hour_width` digits and grow beyond that as needed. This is
+/// what [`serialize`] uses with an hour width of 2, always showing hours.
+///
+/// # Arguments
+///
+/// * `duration` - The duration to format
+/// * `hour_width` - The minimum number of digits of the hour field
+/// * `always_show_hours` - Whether to show the hours of a duration shorter
+///   than an hour, or to format it as "MM:SS"
+///
+/// # Returns
+///
+/// The formatted clock time.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use serde_duration::clock::format_clock;
+///
+/// let duration = Duration::from_secs(30);
+/// assert_eq!(format_clock(&duration, 2, true), "00:00:30");
+/// assert_eq!(format_clock(&duration, 1, true), "0:00:30");
+/// assert_eq!(format_clock(&duration, 2, false), "00:30");
+/// assert_eq!(format_clock(&Duration::from_secs(360_000), 2, false), "100:00:00");
+/// ```
+pub fn format_clock(duration: &Duration, hour_width: usize, always_show_hours: bool) -> String {
+    let secs = duration.as_secs();
+    let (hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+    let nanos = duration.subsec_nanos();
+
+    let clock = if hours == 0 && !always_show_hours {
+        format!("{:02}:{:02}", minutes, seconds)
+    } else {
+        format!(
+            "{:0width$}:{:02}:{:02}",
+            hours,
+            minutes,
+            seconds,
+            width = hour_width
+        )
+    };
+    if nanos == 0 {
+        return clock;
+    }
+    let fraction = format!("{:09}", nanos);
+    format!("{}.{}", clock, fraction.trim_end_matches('0'))
+}
+
+fn parse_clock(s: &str) -> Result<Duration, InvalidDurationError> {
+    parse_fields(trim(s)).map_err(|reason| InvalidDurationError::new(s, reason))
+}
+
+fn parse_fields(s: &str) -> Result<Duration, DurationParseError> {
+    if s.is_empty() {
+        return Err(DurationParseError::EmptyInput);
+    }
+    let mut fields = s.split(':');
+    let (hours, minutes, seconds) = match (fields.next(), fields.next(), fields.next()) {
+        (Some(minutes), Some(seconds), None) => (None, minutes, seconds),
+        (Some(hours), Some(minutes), Some(seconds)) if fields.next().is_none() => {
+            (Some(hours), minutes, seconds)
+        }
+        _ => {
+            return Err(DurationParseError::InvalidFormat(
+                "a clock time must be \"HH:MM:SS\" or \"MM:SS\"",
+            ))
+        }
+    };
+
+    // Minutes are only bounded when they follow hours, see the module docs
+    let minutes = digits(minutes)?;
+    if hours.is_some() {
+        check_range(minutes, "minutes must be between 0 and 59")?;
+    }
+    let hours = match hours {
+        Some(hours) => parse_value(digits(hours)?, Unit::Hours)?,
+        None => Duration::ZERO,
+    };
+    let minutes = parse_value(minutes, Unit::Minutes)?;
+    let whole_seconds = match seconds.split_once('.') {
+        Some((whole, _)) => whole,
+        None => seconds,
+    };
+    check_range(digits(whole_seconds)?, "seconds must be between 0 and 59")?;
+    let seconds = parse_value(seconds, Unit::Seconds)?;
+
+    hours
+        .checked_add(minutes)
+        .and_then(|total| total.checked_add(seconds))
+        .ok_or(DurationParseError::Overflow)
+}
+
+/// Checks that a clock field is a non-empty run of ASCII digits.
+fn digits(field: &str) -> Result<&str, DurationParseError> {
+    if field.is_empty() || !field.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(DurationParseError::InvalidNumber(field.into()));
+    }
+    Ok(field)
+}
+
+/// Checks that a field of digits that follows another field is below 60.
+fn check_range(field: &str, message: &'static str) -> Result<(), DurationParseError> {
+    match field.parse::<u64>() {
+        Ok(value) if value < 60 => Ok(()),
+        _ => Err(DurationParseError::InvalidFormat(message)),
+    }
+}
@@ -0,0 +1,60 @@
+//! Lossless compound serialization of `Duration` values.
+//!
+//! Durations are written with every non-zero unit, e.g. "1m30s" or
+//! "1h1m1s", so that they always deserialize back to the same value. Units go
+//! down to nanoseconds, so 1.5 seconds are written as "1s500ms".
+//!
+//! # Examples
+//!
+//! ```
+//! use core::time::Duration;
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct MyConfig {
+//!     #[serde(with = "serde_duration::compound")]
+//!     timeout: Duration,
+//! }
+//!
+//! let config = MyConfig { timeout: Duration::from_secs(90) };
+//! assert_eq!(serde_json::to_string(&config).unwrap(), r#"{"timeout":"1m30s"}"#);
+//! ```
+
+use core::time::Duration;
+use serde::{Deserializer, Serializer};
+
+/// Serializes a duration to a compound string such as "1h1m1s".
+///
+/// # Arguments
+///
+/// * `duration` - The duration to serialize
+/// * `serializer` - The serde serializer
+///
+/// # Returns
+///
+/// A result containing the serialized string if serialization was successful,
+/// or an error if serialization failed.
+pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&crate::format_duration_compound(duration))
+}
+
+/// Deserializes a duration from a string, accepting everything that
+/// [`crate::deserialize`] accepts.
+///
+/// # Arguments
+///
+/// * `deserializer` - The serde deserializer
+///
+/// # Returns
+///
+/// A result containing the deserialized duration if deserialization was successful,
+/// or an error if deserialization failed.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    crate::deserialize(deserializer)
+}
@@ -0,0 +1,181 @@
+//! A `Duration` wrapper whose default unit for bare numbers is part of its
+//! type.
+//!
+//! [`DurationWithDefault`] reads strings that are a bare number, such as
+//! "30", in the unit named by its type parameter, so each field can pick its
+//! own default without writing a module with
+//! [`serde_duration_with!`](crate::serde_duration_with). The unit is one of
+//! the marker types in this module.
+//!
+//! # Example
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use serde_duration::default_unit::{Millis, Seconds};
+//! use serde_duration::DurationWithDefault;
+//! use std::time::Duration;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct MyConfig {
+//!     timeout: DurationWithDefault<Seconds>,
+//!     poll_interval: DurationWithDefault<Millis>,
+//! }
+//!
+//! let config: MyConfig =
+//!     serde_json::from_str(r#"{"timeout": "30", "poll_interval": "250"}"#).unwrap();
+//! assert_eq!(*config.timeout, Duration::from_secs(30));
+//! assert_eq!(*config.poll_interval, Duration::from_millis(250));
+//! ```
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::Deref;
+use core::time::Duration;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{DurationVisitor, Unit};
+
+/// Names the unit a [`DurationWithDefault`] applies to bare numbers.
+pub trait DefaultUnit {
+    /// The unit applied to strings that are a bare number.
+    const UNIT: Unit;
+}
+
+macro_rules! default_units {
+    ($($(#[$doc:meta])* $name:ident,)*) => {
+        $(
+            $(#[$doc])*
+            #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            pub struct $name;
+
+            impl DefaultUnit for $name {
+                const UNIT: Unit = Unit::$name;
+            }
+        )*
+    };
+}
+
+default_units! {
+    /// Bare numbers are nanoseconds.
+    Nanos,
+    /// Bare numbers are microseconds.
+    Micros,
+    /// Bare numbers are milliseconds.
+    Millis,
+    /// Bare numbers are seconds.
+    Seconds,
+    /// Bare numbers are minutes.
+    Minutes,
+    /// Bare numbers are hours.
+    Hours,
+    /// Bare numbers are days.
+    Days,
+    /// Bare numbers are weeks.
+    Weeks,
+}
+
+/// A `Duration` that deserializes strings that are a bare number in the unit
+/// `U`, and anything else like [`crate::deserialize`].
+///
+/// It always serializes with a unit, the same as [`crate::serialize`], so the
+/// default only matters for input. As with the `default_unit` option of
+/// [`serde_duration_with!`](crate::serde_duration_with), numbers that aren't
+/// in a string are still read as seconds.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use serde_duration::default_unit::Minutes;
+/// use serde_duration::DurationWithDefault;
+///
+/// let ttl: DurationWithDefault<Minutes> = serde_json::from_str(r#""90""#).unwrap();
+/// assert_eq!(*ttl, Duration::from_secs(5400));
+/// assert_eq!(serde_json::to_string(&ttl).unwrap(), r#""90m""#);
+/// ```
+pub struct DurationWithDefault<U> {
+    /// The wrapped duration.
+    pub duration: Duration,
+    unit: PhantomData<U>,
+}
+
+impl<U> DurationWithDefault<U> {
+    /// Wraps `duration`.
+    pub const fn new(duration: Duration) -> Self {
+        DurationWithDefault {
+            duration,
+            unit: PhantomData,
+        }
+    }
+}
+
+// These are written out rather than derived, since deriving would require `U`
+// to implement each trait too.
+
+impl<U> fmt::Debug for DurationWithDefault<U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DurationWithDefault")
+            .field(&self.duration)
+            .finish()
+    }
+}
+
+impl<U> Clone for DurationWithDefault<U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<U> Copy for DurationWithDefault<U> {}
+
+impl<U> Default for DurationWithDefault<U> {
+    fn default() -> Self {
+        DurationWithDefault::new(Duration::ZERO)
+    }
+}
+
+impl<U> PartialEq for DurationWithDefault<U> {
+    fn eq(&self, other: &Self) -> bool {
+        self.duration == other.duration
+    }
+}
+
+impl<U> Eq for DurationWithDefault<U> {}
+
+impl<U> From<Duration> for DurationWithDefault<U> {
+    fn from(duration: Duration) -> Self {
+        DurationWithDefault::new(duration)
+    }
+}
+
+impl<U> Deref for DurationWithDefault<U> {
+    type Target = Duration;
+
+    fn deref(&self) -> &Duration {
+        &self.duration
+    }
+}
+
+impl<U> Serialize for DurationWithDefault<U> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        crate::serialize(&self.duration, serializer)
+    }
+}
+
+impl<'de, U: DefaultUnit> Deserialize<'de> for DurationWithDefault<U> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        DurationVisitor {
+            default_unit: U::UNIT,
+            ..DurationVisitor::default()
+        }
+        .deserialize(deserializer)
+        .map(DurationWithDefault::new)
+    }
+}
@@ -0,0 +1,221 @@
+use alloc::string::{String, ToString};
+use core::fmt;
+use core::ops::Deref;
+use core::str::FromStr;
+use core::time::Duration;
+
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    duration_to_str, parse_duration_with_default, DurationVisitor, InvalidDurationError, Unit,
+};
+
+/// A `Duration` that remembers the string it was parsed from, so that saving
+/// a config writes back exactly what the user wrote.
+///
+/// [`crate::serialize`] writes 60 seconds as "1m", so a config with "60s"
+/// would change on every load and save. A `DurationStr` read from "60s"
+/// serializes as "60s" again, as long as its duration hasn't been changed
+/// since. Changed or newly created durations are written as
+/// [`crate::serialize`] would.
+///
+/// Two `DurationStr`s are equal if their durations are, however they were
+/// written.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use serde_duration::DurationStr;
+///
+/// let mut timeout: DurationStr = serde_json::from_str(r#""60s""#).unwrap();
+/// assert_eq!(*timeout, Duration::from_secs(60));
+/// assert_eq!(serde_json::to_string(&timeout).unwrap(), r#""60s""#);
+///
+/// timeout.set(Duration::from_secs(120));
+/// assert_eq!(serde_json::to_string(&timeout).unwrap(), r#""2m""#);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DurationStr {
+    duration: Duration,
+    /// The string the duration was parsed from, if it was and the duration
+    /// hasn't been changed since.
+    original: Option<String>,
+}
+
+impl DurationStr {
+    /// Wraps `duration`, which has no original string.
+    pub const fn new(duration: Duration) -> Self {
+        DurationStr {
+            duration,
+            original: None,
+        }
+    }
+
+    /// Replaces the duration. The original string is forgotten unless the
+    /// duration stays the same.
+    pub fn set(&mut self, duration: Duration) {
+        if duration != self.duration {
+            self.duration = duration;
+            self.original = None;
+        }
+    }
+
+    /// The string this was parsed from, if any.
+    pub fn original(&self) -> Option<&str> {
+        self.original.as_deref()
+    }
+}
+
+impl PartialEq for DurationStr {
+    fn eq(&self, other: &Self) -> bool {
+        self.duration == other.duration
+    }
+}
+
+impl Eq for DurationStr {}
+
+impl From<Duration> for DurationStr {
+    fn from(duration: Duration) -> Self {
+        DurationStr::new(duration)
+    }
+}
+
+impl From<DurationStr> for Duration {
+    fn from(duration: DurationStr) -> Self {
+        duration.duration
+    }
+}
+
+impl From<DurationStr> for String {
+    fn from(duration: DurationStr) -> Self {
+        match duration.original {
+            Some(original) => original,
+            None => duration_to_str(&duration.duration),
+        }
+    }
+}
+
+impl TryFrom<&str> for DurationStr {
+    type Error = InvalidDurationError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl FromStr for DurationStr {
+    type Err = InvalidDurationError;
+
+    /// Parses `s` as it would be deserialized, so a bare number is a number
+    /// of seconds.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(DurationStr {
+            duration: parse_duration_with_default(s, Unit::Seconds)?,
+            original: Some(s.to_string()),
+        })
+    }
+}
+
+impl Deref for DurationStr {
+    type Target = Duration;
+
+    fn deref(&self) -> &Duration {
+        &self.duration
+    }
+}
+
+impl fmt::Display for DurationStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.original {
+            Some(original) => f.write_str(original),
+            None => f.write_str(&duration_to_str(&self.duration)),
+        }
+    }
+}
+
+impl Serialize for DurationStr {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match &self.original {
+            Some(original) => serializer.serialize_str(original),
+            None => crate::serialize(&self.duration, serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for DurationStr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(DurationStrVisitor)
+        } else {
+            deserializer.deserialize_str(DurationStrVisitor)
+        }
+    }
+}
+
+/// Visitor keeping the string it parses, which may be a quoted number of
+/// seconds as for [`crate::deserialize`]. Numbers that aren't quoted are
+/// accepted too, but have no original string.
+struct DurationStrVisitor;
+
+impl<'de> Visitor<'de> for DurationStrVisitor {
+    type Value = DurationStr;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        DurationVisitor::default().expecting(f)
+    }
+
+    fn visit_str<E>(self, s: &str) -> Result<DurationStr, E>
+    where
+        E: de::Error,
+    {
+        let duration = DurationVisitor::default().visit_str(s)?;
+        Ok(DurationStr {
+            duration,
+            original: Some(s.to_string()),
+        })
+    }
+
+    fn visit_u64<E>(self, secs: u64) -> Result<DurationStr, E>
+    where
+        E: de::Error,
+    {
+        DurationVisitor::default()
+            .visit_u64(secs)
+            .map(DurationStr::new)
+    }
+
+    fn visit_i64<E>(self, secs: i64) -> Result<DurationStr, E>
+    where
+        E: de::Error,
+    {
+        DurationVisitor::default()
+            .visit_i64(secs)
+            .map(DurationStr::new)
+    }
+
+    fn visit_f64<E>(self, secs: f64) -> Result<DurationStr, E>
+    where
+        E: de::Error,
+    {
+        DurationVisitor::default()
+            .visit_f64(secs)
+            .map(DurationStr::new)
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<DurationStr, A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        DurationVisitor::default()
+            .visit_map(map)
+            .map(DurationStr::new)
+    }
+}
@@ -0,0 +1,75 @@
+use alloc::string::String;
+use core::fmt;
+use core::ops::Deref;
+use core::str::FromStr;
+use core::time::Duration;
+
+use crate::{duration_to_str, parse_duration, InvalidDurationError};
+
+/// A `Duration` that can be parsed from a duration string such as "30s" with
+/// [`str::parse`], e.g. for command line arguments.
+///
+/// It displays as the same string [`crate::serialize`] would produce, so
+/// `to_string` and `parse` round-trip.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use serde_duration::DurationString;
+///
+/// let timeout: DurationString = "1h30m".parse().unwrap();
+/// assert_eq!(*timeout, Duration::from_secs(5400));
+/// assert_eq!(timeout.as_secs(), 5400);
+/// assert_eq!(timeout.to_string(), "90m");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DurationString(pub Duration);
+
+impl FromStr for DurationString {
+    type Err = InvalidDurationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_duration(s).map(DurationString)
+    }
+}
+
+impl TryFrom<&str> for DurationString {
+    type Error = InvalidDurationError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<Duration> for DurationString {
+    fn from(duration: Duration) -> Self {
+        DurationString(duration)
+    }
+}
+
+impl From<DurationString> for Duration {
+    fn from(duration: DurationString) -> Self {
+        duration.0
+    }
+}
+
+impl From<DurationString> for String {
+    fn from(duration: DurationString) -> Self {
+        duration_to_str(&duration.0)
+    }
+}
+
+impl Deref for DurationString {
+    type Target = Duration;
+
+    fn deref(&self) -> &Duration {
+        &self.0
+    }
+}
+
+impl fmt::Display for DurationString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&duration_to_str(&self.0))
+    }
+}
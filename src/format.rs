@@ -0,0 +1,157 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::time::Duration;
+
+use crate::{format_in_unit, RoundingMode, Unit};
+
+/// A configurable formatter, for when none of the `format_duration*`
+/// functions produce quite the right string.
+///
+/// By default it formats exactly like [`format_duration`], in the largest
+/// unit up to weeks that the duration is a whole number of. Each builder
+/// method changes one aspect:
+///
+/// * [`compound`](DurationFormat::compound) lists every non-zero unit, as in
+///   "1h30m", rather than using a single unit.
+/// * [`unit`](DurationFormat::unit) always uses the given unit.
+/// * [`smallest_unit`](DurationFormat::smallest_unit) rounds the duration to
+///   a whole number of the given unit first, e.g. to drop sub-second noise.
+/// * [`largest_unit`](DurationFormat::largest_unit) caps the units used, e.g.
+///   to write hours rather than days, or allows months and years.
+/// * [`rounding`](DurationFormat::rounding) sets how the duration is rounded
+///   for `unit` and `smallest_unit`, truncating by default.
+///
+/// A zero duration is always formatted as "0s".
+///
+/// [`format_duration`]: crate::format_duration
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use serde_duration::{DurationFormat, RoundingMode, Unit};
+///
+/// let duration = Duration::from_millis(5_400_250);
+/// assert_eq!(DurationFormat::new().format(&duration), "5400250ms");
+/// assert_eq!(
+///     DurationFormat::new()
+///         .compound(true)
+///         .smallest_unit(Unit::Seconds)
+///         .format(&duration),
+///     "1h30m"
+/// );
+/// assert_eq!(
+///     DurationFormat::new()
+///         .unit(Unit::Hours)
+///         .rounding(RoundingMode::Ceil)
+///         .format(&duration),
+///     "2h"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DurationFormat {
+    compound: bool,
+    unit: Option<Unit>,
+    smallest_unit: Unit,
+    largest_unit: Unit,
+    rounding: RoundingMode,
+}
+
+impl DurationFormat {
+    /// Creates the default format, the same as [`crate::format_duration`].
+    pub const fn new() -> Self {
+        DurationFormat {
+            compound: false,
+            unit: None,
+            smallest_unit: Unit::Nanos,
+            largest_unit: Unit::Weeks,
+            rounding: RoundingMode::Truncate,
+        }
+    }
+
+    /// Sets whether every non-zero unit is listed, as in "1h30m15s", rather
+    /// than a single unit.
+    pub const fn compound(mut self, compound: bool) -> Self {
+        self.compound = compound;
+        self
+    }
+
+    /// Always formats in `unit`, rounding away any remainder. This takes
+    /// precedence over the other unit settings.
+    pub const fn unit(mut self, unit: Unit) -> Self {
+        self.unit = Some(unit);
+        self
+    }
+
+    /// Rounds the duration to a whole number of `unit`, nanoseconds by
+    /// default, before formatting it.
+    pub const fn smallest_unit(mut self, unit: Unit) -> Self {
+        self.smallest_unit = unit;
+        self
+    }
+
+    /// Sets the largest unit used, weeks by default. Months and years are
+    /// only used if this allows them.
+    pub const fn largest_unit(mut self, unit: Unit) -> Self {
+        self.largest_unit = unit;
+        self
+    }
+
+    /// Sets how the duration is rounded to a whole number of the unit set by
+    /// [`unit`](DurationFormat::unit) or
+    /// [`smallest_unit`](DurationFormat::smallest_unit).
+    pub const fn rounding(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Formats `duration` as configured.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - The duration to format
+    ///
+    /// # Returns
+    ///
+    /// The string representation of the duration.
+    pub fn format(&self, duration: &Duration) -> String {
+        let nanos = duration.as_nanos();
+        if let Some(unit) = self.unit {
+            return format_in_unit(self.rounding.divide(nanos, unit.nanos()), unit);
+        }
+
+        let step = self.smallest_unit.nanos();
+        let mut nanos = self.rounding.divide(nanos, step) * step;
+        if nanos == 0 {
+            return "0s".to_string();
+        }
+        // A largest unit below the smallest one would leave nothing to use.
+        let largest = self.largest_unit.nanos().max(step);
+        let mut units = Unit::ALL
+            .into_iter()
+            .filter(|unit| (step..=largest).contains(&unit.nanos()));
+
+        if !self.compound {
+            // `nanos` is a multiple of the smallest unit, so one always fits.
+            let unit = units
+                .find(|unit| nanos.is_multiple_of(unit.nanos()))
+                .unwrap_or(self.smallest_unit);
+            return format!("{}{}", nanos / unit.nanos(), unit.suffix());
+        }
+        let mut s = String::new();
+        for unit in units {
+            let value = nanos / unit.nanos();
+            if value > 0 {
+                s.push_str(&format!("{}{}", value, unit.suffix()));
+                nanos %= unit.nanos();
+            }
+        }
+        s
+    }
+}
+
+impl Default for DurationFormat {
+    fn default() -> Self {
+        DurationFormat::new()
+    }
+}
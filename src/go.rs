@@ -0,0 +1,180 @@
+//! Serialization of `Duration` values in the format used by Go's `time`
+//! package.
+//!
+//! Parsing follows Go's `time.ParseDuration`: one or more numbers, each with
+//! an optional fraction and a unit of "ns", "us" (or "µs"/"μs"), "ms", "s",
+//! "m" or "h", as in "1h30m0s" or "1.5ms". Unlike the crate root, units are
+//! case-sensitive, whitespace isn't allowed and a bare "0" is accepted.
+//! Negative durations are rejected since `Duration` can't represent them.
+//!
+//! Serialization produces the same string as Go's `Duration.String`, e.g.
+//! "1h30m0s", "1.5s" or "500µs", which makes configs shared with Go services,
+//! Kubernetes or Prometheus round-trip unchanged.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::time::Duration;
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct MyConfig {
+//!     #[serde(with = "serde_duration::go")]
+//!     interval: Duration,
+//! }
+//!
+//! let config: MyConfig = serde_json::from_str(r#"{"interval": "1h30m"}"#).unwrap();
+//! assert_eq!(config.interval, Duration::from_secs(5400));
+//! assert_eq!(serde_json::to_string(&config).unwrap(), r#"{"interval":"1h30m0s"}"#);
+//! ```
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt::Write;
+use core::time::Duration;
+use serde::{Deserializer, Serializer};
+
+use crate::{
+    parse_value, write_fraction, DurationParseError, InvalidDurationError, StrVisitor, Unit,
+};
+
+/// Units understood by Go's `time.ParseDuration`, which are case-sensitive.
+const GO_UNITS: &[(&str, Unit)] = &[
+    ("ns", Unit::Nanos),
+    ("us", Unit::Micros),
+    // U+00B5 MICRO SIGN and U+03BC GREEK SMALL LETTER MU
+    ("µs", Unit::Micros),
+    ("μs", Unit::Micros),
+    ("ms", Unit::Millis),
+    ("s", Unit::Seconds),
+    ("m", Unit::Minutes),
+    ("h", Unit::Hours),
+];
+
+/// Serializes a duration the way Go's `Duration.String` formats it, e.g.
+/// "1h30m0s".
+///
+/// # Arguments
+///
+/// * `duration` - The duration to serialize
+/// * `serializer` - The serde serializer
+///
+/// # Returns
+///
+/// A result containing the serialized string if serialization was successful,
+/// or an error if serialization failed.
+pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&format_go(duration))
+}
+
+/// Deserializes a duration from a string accepted by Go's
+/// `time.ParseDuration`.
+///
+/// # Arguments
+///
+/// * `deserializer` - The serde deserializer
+///
+/// # Returns
+///
+/// A result containing the deserialized duration if deserialization was successful,
+/// or an error if deserialization failed.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_str(StrVisitor {
+        expecting: "a Go duration string such as \"1h30m0s\"",
+        parse: parse_go,
+    })
+}
+
+fn format_go(duration: &Duration) -> String {
+    let nanos = duration.as_nanos();
+    let mut s = String::new();
+    if nanos == 0 {
+        s.push_str("0s");
+    } else if nanos < 1_000 {
+        let _ = write!(s, "{}ns", nanos);
+    } else if nanos < 1_000_000 {
+        write_fraction(&mut s, nanos, 3);
+        s.push_str("µs");
+    } else if nanos < 1_000_000_000 {
+        write_fraction(&mut s, nanos, 6);
+        s.push_str("ms");
+    } else {
+        let secs = duration.as_secs();
+        let (hours, minutes) = (secs / 3600, secs / 60 % 60);
+        if hours > 0 {
+            let _ = write!(s, "{}h", hours);
+        }
+        if secs >= 60 {
+            let _ = write!(s, "{}m", minutes);
+        }
+        let seconds = u128::from(secs % 60) * 1_000_000_000 + u128::from(duration.subsec_nanos());
+        write_fraction(&mut s, seconds, 9);
+        s.push('s');
+    }
+    s
+}
+
+fn parse_go(s: &str) -> Result<Duration, InvalidDurationError> {
+    parse_go_tokens(s).map_err(|reason| InvalidDurationError::new(s, reason))
+}
+
+fn parse_go_tokens(s: &str) -> Result<Duration, DurationParseError> {
+    let mut rest = match s.as_bytes().first() {
+        None => return Err(DurationParseError::EmptyInput),
+        Some(b'-') => return Err(DurationParseError::Negative),
+        Some(b'+') => &s[1..],
+        Some(_) => s,
+    };
+    // Go special-cases a lone zero, which needs no unit
+    if rest == "0" {
+        return Ok(Duration::ZERO);
+    }
+    if rest.is_empty() {
+        return Err(DurationParseError::InvalidNumber(String::new()));
+    }
+
+    let mut total = Duration::ZERO;
+    while !rest.is_empty() {
+        let number_end = rest
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(rest.len());
+        let (number, tail) = rest.split_at(number_end);
+        let unit_end = tail
+            .find(|c: char| c.is_ascii_digit() || c == '.')
+            .unwrap_or(tail.len());
+        let (suffix, tail) = tail.split_at(unit_end);
+
+        let unit = match GO_UNITS.iter().find(|(known, _)| *known == suffix) {
+            Some((_, unit)) => *unit,
+            None if suffix.is_empty() => return Err(DurationParseError::MissingUnit),
+            None => return Err(DurationParseError::UnknownUnit(suffix.to_string())),
+        };
+        total = total
+            .checked_add(parse_value(&normalize_number(number, suffix)?, unit)?)
+            .ok_or(DurationParseError::Overflow)?;
+        rest = tail;
+    }
+    Ok(total)
+}
+
+/// Rewrites the numbers Go accepts but `parse_value` doesn't, ".5" and "5.",
+/// into "0.5" and "5". A number with no digits is missing the value of the
+/// unit `suffix`.
+fn normalize_number(number: &str, suffix: &str) -> Result<String, DurationParseError> {
+    let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+    if whole.is_empty() && fraction.is_empty() {
+        return Err(DurationParseError::MissingValue(suffix.to_string()));
+    }
+    let whole = if whole.is_empty() { "0" } else { whole };
+    Ok(if fraction.is_empty() {
+        whole.to_string()
+    } else {
+        format!("{}.{}", whole, fraction)
+    })
+}
@@ -0,0 +1,213 @@
+//! Serialization of `Duration` values in the format of the `humantime` crate.
+//!
+//! This matches `humantime::format_duration` byte for byte, e.g.
+//! "2h 37m 5s" or "1year 2months 3days", and parses the same grammar as
+//! `humantime::parse_duration`, easing the migration of configs written for
+//! it. Requires the `humantime` feature.
+//!
+//! The grammar differs from the crate root's in a few ways:
+//!
+//! * Units are case-sensitive, and "M" means months rather than minutes.
+//! * A month is 30.44 days and a year 365.25 days.
+//! * Numbers are whole; fractions such as "1.5h" are rejected.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::time::Duration;
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct MyConfig {
+//!     #[serde(with = "serde_duration::humantime")]
+//!     timeout: Duration,
+//! }
+//!
+//! let config: MyConfig = serde_json::from_str(r#"{"timeout": "2h 37m 5s"}"#).unwrap();
+//! assert_eq!(config.timeout, Duration::from_secs(9425));
+//! assert_eq!(serde_json::to_string(&config).unwrap(), r#"{"timeout":"2h 37m 5s"}"#);
+//! ```
+
+use alloc::string::{String, ToString};
+use core::fmt::Write;
+use core::time::Duration;
+use serde::{Deserializer, Serializer};
+
+use crate::{nanos_to_duration, DurationParseError, InvalidDurationError, StrVisitor};
+
+const SECS_PER_MONTH: u64 = 2_630_016;
+const SECS_PER_YEAR: u64 = 31_557_600;
+
+/// Units understood by `humantime::parse_duration` and their length in
+/// nanoseconds. Matched case-sensitively.
+const HUMANTIME_UNITS: &[(&str, u128)] = &[
+    ("nanos", 1),
+    ("nsec", 1),
+    ("ns", 1),
+    ("usec", 1_000),
+    ("us", 1_000),
+    ("millis", 1_000_000),
+    ("msec", 1_000_000),
+    ("ms", 1_000_000),
+    ("seconds", 1_000_000_000),
+    ("second", 1_000_000_000),
+    ("secs", 1_000_000_000),
+    ("sec", 1_000_000_000),
+    ("s", 1_000_000_000),
+    ("minutes", 60_000_000_000),
+    ("minute", 60_000_000_000),
+    ("min", 60_000_000_000),
+    ("mins", 60_000_000_000),
+    ("m", 60_000_000_000),
+    ("hours", 3_600_000_000_000),
+    ("hour", 3_600_000_000_000),
+    ("hr", 3_600_000_000_000),
+    ("hrs", 3_600_000_000_000),
+    ("h", 3_600_000_000_000),
+    ("days", 86_400_000_000_000),
+    ("day", 86_400_000_000_000),
+    ("d", 86_400_000_000_000),
+    ("weeks", 604_800_000_000_000),
+    ("week", 604_800_000_000_000),
+    ("w", 604_800_000_000_000),
+    ("months", SECS_PER_MONTH as u128 * 1_000_000_000),
+    ("month", SECS_PER_MONTH as u128 * 1_000_000_000),
+    ("M", SECS_PER_MONTH as u128 * 1_000_000_000),
+    ("years", SECS_PER_YEAR as u128 * 1_000_000_000),
+    ("year", SECS_PER_YEAR as u128 * 1_000_000_000),
+    ("y", SECS_PER_YEAR as u128 * 1_000_000_000),
+];
+
+/// Serializes a duration like `humantime::format_duration`, e.g. "2h 37m 5s".
+///
+/// # Arguments
+///
+/// * `duration` - The duration to serialize
+/// * `serializer` - The serde serializer
+///
+/// # Returns
+///
+/// A result containing the serialized string if serialization was successful,
+/// or an error if serialization failed.
+pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&format_humantime(duration))
+}
+
+/// Deserializes a duration from a string in the grammar of
+/// `humantime::parse_duration`, e.g. "2h 37m 5s" or "1M".
+///
+/// # Arguments
+///
+/// * `deserializer` - The serde deserializer
+///
+/// # Returns
+///
+/// A result containing the deserialized duration if deserialization was successful,
+/// or an error if deserialization failed.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_str(StrVisitor {
+        expecting: "a humantime duration string such as \"2h 37m 5s\"",
+        parse: parse_humantime,
+    })
+}
+
+fn format_humantime(duration: &Duration) -> String {
+    let secs = duration.as_secs();
+    let nanos = duration.subsec_nanos();
+    if secs == 0 && nanos == 0 {
+        return "0s".to_string();
+    }
+
+    let year_secs = secs % SECS_PER_YEAR;
+    let month_secs = year_secs % SECS_PER_MONTH;
+    let day_secs = month_secs % 86400;
+    // Years, months and days are spelled out and pluralized, the rest aren't
+    let items: [(u64, &str, bool); 9] = [
+        (secs / SECS_PER_YEAR, "year", true),
+        (year_secs / SECS_PER_MONTH, "month", true),
+        (month_secs / 86400, "day", true),
+        (day_secs / 3600, "h", false),
+        (day_secs % 3600 / 60, "m", false),
+        (day_secs % 60, "s", false),
+        (u64::from(nanos / 1_000_000), "ms", false),
+        (u64::from(nanos / 1000 % 1000), "us", false),
+        (u64::from(nanos % 1000), "ns", false),
+    ];
+
+    let mut s = String::new();
+    for (value, name, plural) in items {
+        if value == 0 {
+            continue;
+        }
+        if !s.is_empty() {
+            s.push(' ');
+        }
+        // Writing to a String can't fail
+        let _ = write!(s, "{}{}", value, name);
+        if plural && value > 1 {
+            s.push('s');
+        }
+    }
+    s
+}
+
+fn parse_humantime(s: &str) -> Result<Duration, InvalidDurationError> {
+    parse_humantime_tokens(s).map_err(|reason| InvalidDurationError::new(s, reason))
+}
+
+fn parse_humantime_tokens(s: &str) -> Result<Duration, DurationParseError> {
+    let is_whitespace = |c: char| c.is_whitespace();
+    let mut rest = s.trim_start_matches(is_whitespace);
+    if rest.is_empty() {
+        return Err(DurationParseError::EmptyInput);
+    }
+
+    let mut total: u128 = 0;
+    while !rest.is_empty() {
+        // Like humantime, whitespace is allowed inside a number
+        let number_end = rest
+            .find(|c: char| !c.is_ascii_digit() && !c.is_whitespace())
+            .unwrap_or(rest.len());
+        let (number, tail) = rest.split_at(number_end);
+        let unit_end = tail
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(tail.len());
+        let (suffix, tail) = tail.split_at(unit_end);
+
+        let digits: String = number.chars().filter(|c| !c.is_whitespace()).collect();
+        if digits.is_empty() {
+            return Err(DurationParseError::InvalidFormat(
+                "expected a number before the unit",
+            ));
+        }
+        let unit_nanos = match HUMANTIME_UNITS.iter().find(|(known, _)| *known == suffix) {
+            Some((_, unit_nanos)) => *unit_nanos,
+            None if suffix.is_empty() && tail.is_empty() => {
+                return Err(DurationParseError::MissingUnit)
+            }
+            None if suffix.is_empty() => {
+                return Err(DurationParseError::InvalidFormat(
+                    "unexpected character, expected a unit",
+                ))
+            }
+            None => return Err(DurationParseError::UnknownUnit(suffix.to_string())),
+        };
+        let value = digits
+            .parse::<u64>()
+            .map_err(|_| DurationParseError::Overflow)?;
+        total = u128::from(value)
+            .checked_mul(unit_nanos)
+            .and_then(|nanos| total.checked_add(nanos))
+            .ok_or(DurationParseError::Overflow)?;
+
+        rest = tail.trim_start_matches(is_whitespace);
+    }
+
+    nanos_to_duration(total).ok_or(DurationParseError::Overflow)
+}
@@ -0,0 +1,73 @@
+//! Serialization of `Duration` values that may be infinite.
+//!
+//! This works like the crate root, except that `Duration::MAX` is written as
+//! "inf" and the strings "inf" and "infinite", in any case, deserialize to
+//! `Duration::MAX`. It suits settings such as timeouts where "infinite" means
+//! that there is no limit, without needing an `Option` or a sentinel value.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::time::Duration;
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct MyConfig {
+//!     #[serde(with = "serde_duration::infinite")]
+//!     timeout: Duration,
+//! }
+//!
+//! let config: MyConfig = serde_json::from_str(r#"{"timeout": "infinite"}"#).unwrap();
+//! assert_eq!(config.timeout, Duration::MAX);
+//! assert_eq!(serde_json::to_string(&config).unwrap(), r#"{"timeout":"inf"}"#);
+//! ```
+
+use core::time::Duration;
+use serde::{Deserializer, Serializer};
+
+use crate::DurationVisitor;
+
+/// Serializes a duration like [`crate::serialize`], except that
+/// `Duration::MAX` is written as "inf".
+///
+/// # Arguments
+///
+/// * `duration` - The duration to serialize
+/// * `serializer` - The serde serializer
+///
+/// # Returns
+///
+/// A result containing the serialized string if serialization was successful,
+/// or an error if serialization failed.
+pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if *duration == Duration::MAX {
+        serializer.serialize_str("inf")
+    } else {
+        crate::serialize(duration, serializer)
+    }
+}
+
+/// Deserializes a duration like [`crate::deserialize`], additionally mapping
+/// "inf" and "infinite" to `Duration::MAX`.
+///
+/// # Arguments
+///
+/// * `deserializer` - The serde deserializer
+///
+/// # Returns
+///
+/// A result containing the deserialized duration if deserialization was successful,
+/// or an error if deserialization failed.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    DurationVisitor {
+        infinite: true,
+        ..DurationVisitor::default()
+    }
+    .deserialize(deserializer)
+}
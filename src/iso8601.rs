@@ -0,0 +1,174 @@
+//! Serialization of `Duration` values as ISO 8601 durations.
+//!
+//! Durations are written as e.g. "PT1H30M15S" or "PT0.5S", using only hours,
+//! minutes and seconds since days are ambiguous around daylight saving time
+//! changes. When parsing, weeks ("P2W") and days ("P1DT2H") are accepted as
+//! 7 and 1 times 24 hours, and any component may have a decimal fraction
+//! using either '.' or ','. Years and months have no fixed length and are
+//! rejected.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::time::Duration;
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct MyConfig {
+//!     #[serde(with = "serde_duration::iso8601")]
+//!     timeout: Duration,
+//! }
+//!
+//! let config: MyConfig = serde_json::from_str(r#"{"timeout": "P1DT2H"}"#).unwrap();
+//! assert_eq!(config.timeout, Duration::from_secs(26 * 3600));
+//! assert_eq!(serde_json::to_string(&config).unwrap(), r#"{"timeout":"PT26H"}"#);
+//! ```
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt::Write;
+use core::time::Duration;
+use serde::{Deserializer, Serializer};
+
+use crate::{parse_value, trim, DurationParseError, InvalidDurationError, StrVisitor, Unit};
+
+/// Serializes a duration as an ISO 8601 duration such as "PT1H30M".
+///
+/// # Arguments
+///
+/// * `duration` - The duration to serialize
+/// * `serializer` - The serde serializer
+///
+/// # Returns
+///
+/// A result containing the serialized string if serialization was successful,
+/// or an error if serialization failed.
+pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&format_iso8601(duration))
+}
+
+/// Deserializes a duration from an ISO 8601 duration such as "PT1H30M" or
+/// "P1DT2H".
+///
+/// # Arguments
+///
+/// * `deserializer` - The serde deserializer
+///
+/// # Returns
+///
+/// A result containing the deserialized duration if deserialization was successful,
+/// or an error if deserialization failed.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_str(StrVisitor {
+        expecting: "an ISO 8601 duration string such as \"PT1H30M\"",
+        parse: parse_iso8601,
+    })
+}
+
+fn format_iso8601(duration: &Duration) -> String {
+    let secs = duration.as_secs();
+    let (hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+    let nanos = duration.subsec_nanos();
+
+    let mut s = "PT".to_string();
+    // Writing to a String can't fail
+    if hours > 0 {
+        let _ = write!(s, "{}H", hours);
+    }
+    if minutes > 0 {
+        let _ = write!(s, "{}M", minutes);
+    }
+    if nanos > 0 {
+        let fraction = format!("{:09}", nanos);
+        let _ = write!(s, "{}.{}S", seconds, fraction.trim_end_matches('0'));
+    } else if seconds > 0 || secs == 0 {
+        let _ = write!(s, "{}S", seconds);
+    }
+    s
+}
+
+fn parse_iso8601(s: &str) -> Result<Duration, InvalidDurationError> {
+    parse_components(trim(s)).map_err(|reason| InvalidDurationError::new(s, reason))
+}
+
+fn parse_components(s: &str) -> Result<Duration, DurationParseError> {
+    if s.is_empty() {
+        return Err(DurationParseError::EmptyInput);
+    }
+    let rest = s
+        .strip_prefix('P')
+        .ok_or(DurationParseError::InvalidFormat(
+            "an ISO 8601 duration must start with 'P'",
+        ))?;
+    let (date, time) = match rest.split_once('T') {
+        Some((date, time)) => (date, Some(time)),
+        None => (rest, None),
+    };
+    if date.contains(['Y', 'M']) {
+        return Err(DurationParseError::InvalidFormat(
+            "years and months have no fixed length and are not supported",
+        ));
+    }
+    if time == Some("") {
+        return Err(DurationParseError::InvalidFormat(
+            "'T' must be followed by a time component",
+        ));
+    }
+    if date.is_empty() && time.is_none() {
+        return Err(DurationParseError::InvalidFormat(
+            "an ISO 8601 duration needs at least one component",
+        ));
+    }
+
+    let date = parse_designated(date, &[('W', Unit::Weeks), ('D', Unit::Days)])?;
+    let time = parse_designated(
+        time.unwrap_or(""),
+        &[
+            ('H', Unit::Hours),
+            ('M', Unit::Minutes),
+            ('S', Unit::Seconds),
+        ],
+    )?;
+    date.checked_add(time).ok_or(DurationParseError::Overflow)
+}
+
+/// Parses a run of components such as "1H30M", where each designator may
+/// appear at most once and only in the order given.
+fn parse_designated(s: &str, designators: &[(char, Unit)]) -> Result<Duration, DurationParseError> {
+    let mut total = Duration::ZERO;
+    let mut allowed = designators;
+    let mut rest = s;
+    while !rest.is_empty() {
+        let number_end = rest
+            .find(|c: char| !c.is_ascii_digit() && c != '.' && c != ',')
+            .unwrap_or(rest.len());
+        let (number, tail) = rest.split_at(number_end);
+        let designator = tail.chars().next().ok_or(DurationParseError::MissingUnit)?;
+        let index = match allowed.iter().position(|(d, _)| *d == designator) {
+            Some(index) => index,
+            None if designators.iter().any(|(d, _)| *d == designator) => {
+                return Err(DurationParseError::InvalidFormat(
+                    "ISO 8601 components must be in order and appear at most once",
+                ))
+            }
+            None => return Err(DurationParseError::UnknownUnit(designator.to_string())),
+        };
+        if number.is_empty() {
+            return Err(DurationParseError::MissingValue(designator.to_string()));
+        }
+
+        let value = parse_value(&number.replace(',', "."), allowed[index].1)?;
+        total = total
+            .checked_add(value)
+            .ok_or(DurationParseError::Overflow)?;
+        allowed = &allowed[index + 1..];
+        rest = &tail[designator.len_utf8()..];
+    }
+    Ok(total)
+}
@@ -1,8 +1,78 @@
 //! This crate provides utility functions for serializing and deserializing
-//! `Duration` objects in a custom format. The format uses the letters
-//! "s", "m", and "h" to denote seconds, minutes, and hours, respectively.
-//! 
-//! For example, "10s" represents a duration of 10 seconds,
+//! `Duration` objects in a custom format. A duration is written as a number,
+//! optionally with a decimal fraction such as "1.5", followed by a unit suffix:
+//!
+//! * "ns" - nanoseconds
+//! * "us" (or "µs") - microseconds
+//! * "ms" - milliseconds
+//! * "s" - seconds
+//! * "m" - minutes
+//! * "h" - hours
+//! * "d" - days (24 hours)
+//! * "w" - weeks (7 days)
+//! * "mo" - months (30 days)
+//! * "y" - years (365 days)
+//!
+//! Months and years are approximations, since calendar months and years vary
+//! in length. They are accepted when parsing, but serialization never picks
+//! them on its own; use [`format_duration_as`] to format in them explicitly.
+//! Their lengths can be changed for parsing with [`ParseOptions`].
+//!
+//! Each unit may also be spelled out in the singular or plural, e.g. "second"
+//! or "seconds", so "10 seconds" and "1 hour" are valid durations too, or
+//! written with one of these common abbreviations:
+//!
+//! * nanoseconds - "nsec", "nsecs", "nanos"
+//! * microseconds - "usec", "usecs", "micros"
+//! * milliseconds - "msec", "msecs", "millis"
+//! * seconds - "sec", "secs"
+//! * minutes - "min", "mins"
+//! * hours - "hr", "hrs"
+//! * weeks - "wk", "wks"
+//! * months - "mos"
+//! * years - "yr", "yrs"
+//!
+//! Serialization always uses the short suffixes above.
+//!
+//! The lowercase short suffixes are canonical, but units are matched
+//! case-insensitively, so "30S" and "5M" are the same as "30s" and "5m". This
+//! is only unambiguous because no two spellings of different units differ
+//! just in case: "M" always means minutes, and months are written "mo",
+//! never "M". Any unit added in the future must keep it that way, which is
+//! checked when the crate is compiled.
+//!
+//! A number needs a unit, except that a bare "0" is accepted as zero, since
+//! it is zero in any unit and a common way to write "disabled". This holds in
+//! [`ParseMode::Strict`] too, where it must be exactly "0". Any other bare
+//! number is rejected by [`parse_duration`] unless a default unit is given,
+//! e.g. with [`parse_duration_with_default`]. [`deserialize`] reads a quoted
+//! number such as "30" as seconds, the same as an unquoted one.
+//!
+//! A duration may start with '+', so "+30s" is the same as "30s". A leading
+//! '-' is rejected because `Duration` can't be negative; only
+//! [`SignedDuration`] and the signed [`chrono`](crate::chrono) and
+//! [`time`](crate::time) modules accept it.
+//!
+//! Long numbers may use '_' as a digit separator, as in "1_000s" or
+//! "10_000ms". Each '_' must sit between two digits.
+//!
+//! Several tokens can be combined into a compound duration whose value is
+//! their sum, so "1h30m15s" represents one hour, thirty minutes and fifteen
+//! seconds. Repeating a unit is allowed and simply adds to the total.
+//! Whitespace may separate numbers from their units and tokens from each
+//! other, as in "30 s" or "1 h 30 m". Short and spelled-out units can be
+//! mixed freely, so "1 hour 30m" is valid, and a unit repeated under a
+//! different spelling is summed like any other, making "1 minute 1m" two
+//! minutes. Zero components, as in the "1h0m0s" that Go writes, are accepted
+//! and add nothing. Only the [`go`] module writes them back out; everything
+//! else leaves zero components out.
+//!
+//! All of these tolerances can be switched off at once with
+//! [`ParseMode::Strict`], which only accepts the canonical form the crate
+//! writes, such as "30s" or "1h30m".
+//!
+//! For example, "500ms" represents a duration of 500 milliseconds,
+//! "10s" represents a duration of 10 seconds,
 //! "5m" represents a duration of 5 minutes, and "3h" represents a duration of
 //! 3 hours. The functions provided by this crate allow you to easily convert
 //! between `Duration` objects and these custom strings.
@@ -12,36 +82,339 @@
 //! ```
 //! use std::time::Duration;
 //! use serde::{Serialize, Deserialize};
-//! 
+//!
 //! use serde_duration;
 //!
 //! #[derive(Serialize, Deserialize)]
-//! struct TestStruct {
+//! struct MyConfig {
 //!     #[serde(with = "serde_duration")]
-//!     duration: Duration,
+//!     timeout: Duration,
 //! }
 //!
 //! let config_str = r#"{"timeout": "30s"}"#;
 //! let config: MyConfig = serde_json::from_str(config_str).unwrap();
 //! assert_eq!(config.timeout, Duration::from_secs(30));
 //! ```
+//!
+//! To configure the format per field, e.g. to always serialize in one unit,
+//! generate a module with the [`serde_duration_with!`] macro:
+//!
+//! ```
+//! serde_duration::serde_duration_with!(mod as_millis, unit = Millis);
+//! ```
+//!
+//! # Features
+//!
+//! * `std` (enabled by default) - implements `std::error::Error` for
+//!   [`InvalidDurationError`]. Without it the crate is `no_std` and only
+//!   needs `alloc`; durations are `core::time::Duration`, which is the same
+//!   type as `std::time::Duration`.
+//! * `chrono` - adds the [`chrono`](crate::chrono) module for
+//!   `chrono::Duration`, which also accepts negative durations such as "-30s".
+//! * `time` - adds the [`time`](crate::time) module for `time::Duration`,
+//!   with the same support for negative durations.
+//! * `humantime` - adds the [`humantime`](crate::humantime) module, which
+//!   reads and writes durations exactly like the `humantime` crate.
+//!
+//! # Errors
+//!
+//! Strings that are not valid durations are reported as an
+//! [`InvalidDurationError`]. It is returned directly by [`parse_duration`] and
+//! wrapped in the format's own error type during deserialization. Its
+//! [`kind`](InvalidDurationError::kind) tells what exactly was wrong as a
+//! [`DurationParseError`].
+//!
+//! Inputs longer than 256 bytes are rejected with
+//! [`DurationParseError::TooLong`] before any parsing is done;
+//! [`ParseOptions::max_len`] changes the limit.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt;
+use core::time::Duration;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(feature = "chrono")]
+pub mod chrono;
+pub mod clock;
+pub mod compound;
+pub mod default_unit;
+mod duration_str;
+mod duration_string;
+mod format;
+pub mod go;
+#[cfg(feature = "humantime")]
+pub mod humantime;
+pub mod infinite;
+pub mod iso8601;
+pub mod map_values;
+pub mod millis;
+pub mod null_as_zero;
+pub mod object;
+pub mod option;
+mod options;
+pub mod pair;
+mod rounding;
+pub mod seconds;
+pub mod secs_number;
+mod signed_duration;
+#[cfg(feature = "time")]
+pub mod time;
+mod tokens;
+pub mod tuple2;
+mod unit;
+pub mod vec;
+#[doc(hidden)]
+pub mod with;
+
+pub use default_unit::DurationWithDefault;
+pub use duration_str::DurationStr;
+pub use duration_string::DurationString;
+pub use format::DurationFormat;
+pub use options::{ParseMode, ParseOptions};
+pub use rounding::RoundingMode;
+pub use signed_duration::SignedDuration;
+pub use tokens::DurationTokens;
+pub use unit::{ParseUnitError, Unit};
+
+/// The error returned when a string is not a valid duration.
+///
+/// With the `std` feature enabled it implements `std::error::Error`, so it can
+/// be propagated with `?` into boxed errors or error types built with crates
+/// such as `anyhow`.
+///
+/// Its message includes the offending input and the reason it was rejected,
+/// e.g. `invalid duration "5x": unknown unit 'x'`. The reason is available as
+/// a [`DurationParseError`] from [`kind`](InvalidDurationError::kind).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidDurationError {
+    input: String,
+    reason: DurationParseError,
+    position: Option<usize>,
+}
+
+/// Why a string was rejected as a duration, as returned by
+/// [`InvalidDurationError::kind`].
+///
+/// Each variant's `Display` is the reason part of the error's message, e.g.
+/// "unknown unit 'x'". More variants may be added as the parser grows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DurationParseError {
+    /// The input was empty or only whitespace.
+    EmptyInput,
+    /// A unit wasn't preceded by a number, as in "s" or "1h m". Holds the
+    /// unit.
+    MissingValue(String),
+    /// A number wasn't followed by a unit, as in "30" or "1h30".
+    MissingUnit,
+    /// A unit wasn't recognized, as in "5x". Holds the unit.
+    UnknownUnit(String),
+    /// A number was malformed, as in "1.2.3s". Holds the number.
+    InvalidNumber(String),
+    /// The input broke a rule of one of the alternative formats, such as
+    /// ISO 8601's, described by the message.
+    InvalidFormat(&'static str),
+    /// The duration doesn't fit in a `Duration`.
+    Overflow,
+    /// The duration had a leading '-', which `Duration` can't represent.
+    Negative,
+    /// A '_' digit separator wasn't between two digits, as in "_1s". Holds
+    /// the number.
+    MisplacedSeparator(String),
+    /// The input was longer than the maximum length in bytes, which this
+    /// holds, and was rejected without being parsed.
+    TooLong(usize),
+    /// In strict mode, a unit appeared twice, as in "1h1h". Holds the second
+    /// occurrence of the unit.
+    DuplicateUnit(String),
+    /// In strict mode, a unit followed a smaller one, as in "30s1h". Holds
+    /// the larger unit.
+    MisorderedUnit(String),
+    /// In [`ParseMode::Strict`], a unit wasn't written in its canonical
+    /// form, as in "30S" or "30 secs". Holds the unit as written.
+    NonCanonicalUnit(String),
+    /// A unit other than the one given to [`parse_duration_in`] was used, as
+    /// in "30m" when reading seconds. Holds the unit as written and the
+    /// expected unit.
+    UnexpectedUnit(String, Unit),
+    /// A signed duration had more than one sign, as in "--30s" or "-+30s".
+    RepeatedSign,
+}
+
+impl InvalidDurationError {
+    fn new(input: &str, reason: DurationParseError) -> Self {
+        InvalidDurationError {
+            input: input.to_string(),
+            reason,
+            position: None,
+        }
+    }
+
+    /// Records the byte offset within the input at which the problem was
+    /// found.
+    fn at(mut self, position: usize) -> Self {
+        self.position = Some(position);
+        self
+    }
 
-use serde::{de, Deserialize, Deserializer, Serializer};
-use std::time::Duration;
+    /// Returns the kind of error, for callers that need to tell e.g. an
+    /// unknown unit from an overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use serde_duration::{parse_duration, DurationParseError};
+    ///
+    /// let error = parse_duration("5x").unwrap_err();
+    /// assert!(matches!(error.kind(), DurationParseError::UnknownUnit(unit) if unit == "x"));
+    /// ```
+    pub fn kind(&self) -> &DurationParseError {
+        &self.reason
+    }
+
+    /// Returns the input that was rejected.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// Returns the byte offset within the input at which the problem was
+    /// found, e.g. 4 for the unknown unit in "1h30x15s", if it is known.
+    ///
+    /// The error's message mentions the position too, unless the problem is at
+    /// the very start of the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let error = serde_duration::parse_duration("1h30x15s").unwrap_err();
+    /// assert_eq!(error.position(), Some(4));
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "invalid duration \"1h30x15s\": unknown unit 'x' at position 4"
+    /// );
+    /// ```
+    pub fn position(&self) -> Option<usize> {
+        self.position
+    }
+
+    /// Builds the error for an input over `max_len` bytes. Only the start of
+    /// the input is kept, since it may be huge.
+    fn too_long(input: &str, max_len: usize) -> Self {
+        let mut end = TOO_LONG_PREFIX_LEN.min(input.len());
+        while !input.is_char_boundary(end) {
+            end -= 1;
+        }
+        let input = if end < input.len() {
+            format!("{}...", &input[..end])
+        } else {
+            input.to_string()
+        };
+        InvalidDurationError {
+            input,
+            reason: DurationParseError::TooLong(max_len),
+            position: None,
+        }
+    }
 
-#[derive(Debug)]
-struct InvalidDurationError;
+    /// Builds the error for an input that `str_to_duration` did not recognize
+    /// as a duration at all, i.e. whose first token has no known unit.
+    fn unrecognized(input: &str) -> Self {
+        let unsigned = trim(input);
+        let unsigned = unsigned.strip_prefix('+').unwrap_or(unsigned);
+        let (_, unit, _) = split_token(unsigned);
+        let reason = if unit.is_empty() {
+            DurationParseError::MissingUnit
+        } else {
+            DurationParseError::UnknownUnit(unit.to_string())
+        };
+        InvalidDurationError::new(input, reason).at(offset(input, unit))
+    }
+}
 
+#[cfg(feature = "std")]
 impl std::error::Error for InvalidDurationError {}
 
-impl std::fmt::Display for InvalidDurationError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Invalid duration format")
+impl fmt::Display for InvalidDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.reason, self.position) {
+            // There's no point in quoting an input that is empty or blank
+            (DurationParseError::EmptyInput, _) => write!(f, "{}", self.reason),
+            // Pointing at the very start of the input adds nothing
+            (_, Some(position)) if position > 0 => write!(
+                f,
+                "invalid duration {:?}: {} at position {}",
+                self.input, self.reason, position
+            ),
+            _ => write!(f, "invalid duration {:?}: {}", self.input, self.reason),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DurationParseError {}
+
+impl fmt::Display for DurationParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DurationParseError::EmptyInput => write!(f, "empty duration"),
+            DurationParseError::MissingValue(unit) => {
+                write!(f, "missing numeric value before unit '{}'", unit)
+            }
+            DurationParseError::MissingUnit => write!(f, "missing unit"),
+            DurationParseError::UnknownUnit(unit) => write!(f, "unknown unit '{}'", unit),
+            DurationParseError::InvalidNumber(number) => write!(f, "invalid number {:?}", number),
+            DurationParseError::InvalidFormat(message) => write!(f, "{}", message),
+            DurationParseError::Overflow => write!(f, "duration is too large"),
+            DurationParseError::MisplacedSeparator(number) => write!(
+                f,
+                "misplaced digit separator in {:?}, '_' must be between digits",
+                number
+            ),
+            DurationParseError::TooLong(max_len) => {
+                write!(
+                    f,
+                    "duration is longer than the maximum of {} bytes",
+                    max_len
+                )
+            }
+            DurationParseError::DuplicateUnit(unit) => {
+                write!(f, "unit '{}' appears more than once", unit)
+            }
+            DurationParseError::MisorderedUnit(unit) => {
+                write!(f, "unit '{}' must come before smaller units", unit)
+            }
+            DurationParseError::NonCanonicalUnit(unit) => {
+                write!(f, "unit '{}' is not allowed in strict mode", unit)
+            }
+            DurationParseError::RepeatedSign => {
+                write!(f, "a duration may only have one sign")
+            }
+            DurationParseError::UnexpectedUnit(unit, expected) => {
+                write!(f, "unit '{}' is not the expected unit '{}'", unit, expected)
+            }
+            DurationParseError::Negative => write!(
+                f,
+                "negative durations are not supported for std::time::Duration"
+            ),
+        }
     }
 }
 
-/// Serializes a duration to a string using the format "Xs", "Xm", or "Xh",
-/// where X is the duration in seconds, minutes, or hours, respectively.
+/// Serializes a duration to a string using the format "Xw", "Xd", "Xh", "Xm",
+/// or "Xs", where X is the duration in weeks, days, hours, minutes, or
+/// seconds, respectively. The largest unit that represents the duration
+/// exactly is used, so 120 seconds become "2m" but 61 seconds stay "61s" and
+/// the result always deserializes back to the same value.
+///
+/// Durations with a sub-second component are written in the largest of "ms",
+/// "us", or "ns" that represents them exactly, e.g. "1500ms" or "750ns".
+/// A zero duration is always written as the canonical "0s".
 ///
 /// # Arguments
 ///
@@ -59,8 +432,12 @@ where
     serializer.serialize_str(&duration_to_str(duration))
 }
 
-/// Deserializes a duration from a string using the format "Xs", "Xm", or "Xh",
-/// where X is the duration in seconds, minutes, or hours, respectively.
+/// Deserializes a duration from a string using the format "X<unit>", where X is
+/// a number such as "30" or "1.5" and unit is one of the suffixes listed in the
+/// crate documentation. A plain number such as `30` or `1.5` is also accepted
+/// in self-describing formats such as JSON, and is interpreted as a number of
+/// seconds. So is a string that is just a number, such as "30", for producers
+/// that quote their numbers.
 ///
 /// # Arguments
 ///
@@ -74,40 +451,1576 @@ pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
 where
     D: Deserializer<'de>,
 {
-    // Deserialize the input string using the given deserializer
-    let s = match String::deserialize(deserializer) {
-        Ok(s) => s,
-        Err(e) => return Err(e),
+    DurationVisitor::default().deserialize(deserializer)
+}
+
+/// Serializes any type that converts into a `Duration` like [`serialize`],
+/// for use with `#[serde(serialize_with = "serde_duration::serialize_as")]`
+/// on fields of your own wrapper types.
+///
+/// `T` must implement `Clone` as well as `Into<Duration>`, since the value is
+/// only borrowed; wrappers around a `Duration` can usually derive `Copy`.
+///
+/// # Arguments
+///
+/// * `value` - The value to serialize
+/// * `serializer` - The serde serializer
+///
+/// # Returns
+///
+/// A result containing the serialized string if serialization was successful,
+/// or an error if serialization failed.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use serde::{Serialize, Deserialize};
+///
+/// #[derive(Clone, Copy)]
+/// struct Timeout(Duration);
+///
+/// impl From<Timeout> for Duration {
+///     fn from(timeout: Timeout) -> Duration {
+///         timeout.0
+///     }
+/// }
+///
+/// impl From<Duration> for Timeout {
+///     fn from(duration: Duration) -> Timeout {
+///         Timeout(duration)
+///     }
+/// }
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct MyConfig {
+///     #[serde(
+///         serialize_with = "serde_duration::serialize_as",
+///         deserialize_with = "serde_duration::deserialize_as"
+///     )]
+///     timeout: Timeout,
+/// }
+///
+/// let config: MyConfig = serde_json::from_str(r#"{"timeout": "30s"}"#).unwrap();
+/// assert_eq!(config.timeout.0, Duration::from_secs(30));
+/// assert_eq!(serde_json::to_string(&config).unwrap(), r#"{"timeout":"30s"}"#);
+/// ```
+pub fn serialize_as<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Clone + Into<Duration>,
+    S: Serializer,
+{
+    serialize(&value.clone().into(), serializer)
+}
+
+/// Deserializes any type that can be created from a `Duration` like
+/// [`deserialize`], for use with
+/// `#[serde(deserialize_with = "serde_duration::deserialize_as")]`. See
+/// [`serialize_as`] for an example.
+///
+/// # Arguments
+///
+/// * `deserializer` - The serde deserializer
+///
+/// # Returns
+///
+/// A result containing the deserialized value if deserialization was
+/// successful, or an error if deserialization failed.
+pub fn deserialize_as<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: From<Duration>,
+    D: Deserializer<'de>,
+{
+    deserialize(deserializer).map(T::from)
+}
+
+/// Visitor accepting either a duration string or a number of seconds.
+struct DurationVisitor {
+    /// The unit applied to strings that are a bare number, seconds by default
+    /// to match numbers that aren't quoted.
+    default_unit: Unit,
+    /// Whether "inf" and "infinite" are accepted as `Duration::MAX`.
+    infinite: bool,
+}
+
+impl Default for DurationVisitor {
+    fn default() -> Self {
+        DurationVisitor {
+            default_unit: Unit::Seconds,
+            infinite: false,
+        }
+    }
+}
+
+impl DurationVisitor {
+    fn deserialize<'de, D>(self, deserializer: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Formats that aren't self-describing can't tell us whether a string or
+        // a number follows, and we only ever serialize strings.
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(self)
+        } else {
+            deserializer.deserialize_str(self)
+        }
+    }
+}
+
+impl<'de> de::Visitor<'de> for DurationVisitor {
+    type Value = Duration;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a duration string such as \"30s\" or a number of seconds")
+    }
+
+    fn visit_str<E>(self, s: &str) -> Result<Duration, E>
+    where
+        E: de::Error,
+    {
+        if self.infinite && is_infinite(s) {
+            return Ok(Duration::MAX);
+        }
+        parse_duration_with_default(s, self.default_unit).map_err(E::custom)
+    }
+
+    fn visit_u64<E>(self, secs: u64) -> Result<Duration, E>
+    where
+        E: de::Error,
+    {
+        Ok(Duration::from_secs(secs))
+    }
+
+    fn visit_i64<E>(self, secs: i64) -> Result<Duration, E>
+    where
+        E: de::Error,
+    {
+        u64::try_from(secs)
+            .map(Duration::from_secs)
+            .map_err(|_| E::invalid_value(de::Unexpected::Signed(secs), &self))
+    }
+
+    fn visit_f64<E>(self, secs: f64) -> Result<Duration, E>
+    where
+        E: de::Error,
+    {
+        // Rejects negative, NaN and out of range values rather than panicking
+        // like `Duration::from_secs_f64` would.
+        Duration::try_from_secs_f64(secs)
+            .map_err(|_| E::invalid_value(de::Unexpected::Float(secs), &self))
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Duration, A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        match arbitrary_precision_number(map)? {
+            Some(number) => self.visit_number(&number),
+            None => Err(de::Error::invalid_type(de::Unexpected::Map, &self)),
+        }
+    }
+}
+
+impl DurationVisitor {
+    /// Converts the text of a JSON number of seconds, such as "1.5", to a
+    /// duration. The text is parsed exactly where possible, and otherwise
+    /// like the number it stands for so that errors match.
+    fn visit_number<E>(self, number: &str) -> Result<Duration, E>
+    where
+        E: de::Error,
+    {
+        use de::Visitor;
+
+        let options = ParseOptions::new().scientific(true);
+        if let Ok(duration) = parse_number(number, 1_000_000_000, &options) {
+            return Ok(duration);
+        }
+        if let Ok(secs) = number.parse::<i64>() {
+            return self.visit_i64(secs);
+        }
+        match number.parse::<f64>() {
+            Ok(secs) => self.visit_f64(secs),
+            Err(_) => Err(E::invalid_value(de::Unexpected::Str(number), &self)),
+        }
+    }
+}
+
+/// The key under which serde_json's `arbitrary_precision` feature passes the
+/// text of a number, as the only entry of a map, to `deserialize_any`.
+const ARBITRARY_PRECISION_TOKEN: &str = "$serde_json::private::Number";
+
+/// Reads the text of a number from a map passed by serde_json with the
+/// `arbitrary_precision` feature enabled, or returns `None` for any other map.
+fn arbitrary_precision_number<'de, A>(mut map: A) -> Result<Option<String>, A::Error>
+where
+    A: de::MapAccess<'de>,
+{
+    match map.next_key::<String>()? {
+        Some(key) if key == ARBITRARY_PRECISION_TOKEN => map.next_value().map(Some),
+        _ => Ok(None),
+    }
+}
+
+/// Visits the number in a map from serde_json's `arbitrary_precision`
+/// feature as a whole number of seconds, as it would be visited without the
+/// feature: through `visit_u64` or `visit_i64`, or as an invalid type.
+fn visit_whole_number<'de, V, A>(visitor: V, map: A) -> Result<V::Value, A::Error>
+where
+    V: de::Visitor<'de>,
+    A: de::MapAccess<'de>,
+{
+    let Some(number) = arbitrary_precision_number(map)? else {
+        return Err(de::Error::invalid_type(de::Unexpected::Map, &visitor));
+    };
+    if let Ok(secs) = number.parse::<u64>() {
+        return visitor.visit_u64(secs);
+    }
+    if let Ok(secs) = number.parse::<i64>() {
+        return visitor.visit_i64(secs);
+    }
+    let unexpected = match number.parse::<f64>() {
+        Ok(secs) => de::Unexpected::Float(secs),
+        Err(_) => de::Unexpected::Str(&number),
     };
-    
-    // Attempt to convert the string to a duration
-    match str_to_duration(&s) {
-        Ok(Some(duration)) => Ok(duration),
-        Ok(None) => Err(de::Error::custom("invalid duration format")),
-        Err(e) => Err(de::Error::custom(e)),
+    Err(de::Error::invalid_type(unexpected, &visitor))
+}
+
+/// Visitor for the alternative string formats, which parses the string in
+/// place so that borrowed input is never copied into a `String`.
+struct StrVisitor {
+    expecting: &'static str,
+    parse: fn(&str) -> Result<Duration, InvalidDurationError>,
+}
+
+impl<'de> de::Visitor<'de> for StrVisitor {
+    type Value = Duration;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.expecting)
+    }
+
+    fn visit_str<E>(self, s: &str) -> Result<Duration, E>
+    where
+        E: de::Error,
+    {
+        check_len(s, DEFAULT_MAX_LEN).map_err(E::custom)?;
+        (self.parse)(s).map_err(E::custom)
+    }
+}
+
+/// Serializes a borrowed duration with [`serialize`], so that durations inside
+/// containers can be handed to the container's own `Serialize` impl.
+struct SerializeDuration<'a>(&'a Duration);
+
+impl Serialize for SerializeDuration<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize(self.0, serializer)
+    }
+}
+
+/// Deserializes a duration with [`deserialize`], so that durations inside
+/// containers can be read through the container's own `Deserialize` impl.
+struct DeserializeDuration(Duration);
+
+impl<'de> Deserialize<'de> for DeserializeDuration {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize(deserializer).map(DeserializeDuration)
+    }
+}
+
+/// Parses a duration from a string such as "30s" or "1h30m", outside of any
+/// serde context.
+///
+/// This accepts exactly what [`deserialize`] accepts, which makes it suitable
+/// for e.g. parsing command line arguments.
+///
+/// # Arguments
+///
+/// * `s` - The string to parse
+///
+/// # Returns
+///
+/// A result containing the parsed duration, or an error if the string is not
+/// a valid duration.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// assert_eq!(serde_duration::parse_duration("30s").unwrap(), Duration::from_secs(30));
+/// assert_eq!(serde_duration::parse_duration("1h30m").unwrap(), Duration::from_secs(5400));
+/// assert!(serde_duration::parse_duration("30").is_err());
+/// ```
+pub fn parse_duration(s: &str) -> Result<Duration, InvalidDurationError> {
+    str_to_duration(s)?.ok_or_else(|| InvalidDurationError::unrecognized(s))
+}
+
+/// Parses a duration like [`parse_duration`], except that a bare number such as
+/// "30" is interpreted in the given default unit instead of being rejected.
+///
+/// # Arguments
+///
+/// * `s` - The string to parse
+/// * `default_unit` - The unit applied to a number without a suffix
+///
+/// # Returns
+///
+/// A result containing the parsed duration, or an error if the string is not
+/// a valid duration.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use serde_duration::{parse_duration_with_default, Unit};
+///
+/// assert_eq!(parse_duration_with_default("30", Unit::Seconds).unwrap(), Duration::from_secs(30));
+/// assert_eq!(parse_duration_with_default("30", Unit::Minutes).unwrap(), Duration::from_secs(1800));
+/// assert_eq!(parse_duration_with_default("1h", Unit::Minutes).unwrap(), Duration::from_secs(3600));
+/// ```
+pub fn parse_duration_with_default(
+    s: &str,
+    default_unit: Unit,
+) -> Result<Duration, InvalidDurationError> {
+    check_len(s, DEFAULT_MAX_LEN)?;
+    let unsigned = trim(s);
+    let unsigned = unsigned.strip_prefix('+').unwrap_or(unsigned);
+    let (number, unit, rest) = split_token(unsigned);
+    if !number.is_empty() && unit.is_empty() && rest.is_empty() {
+        return parse_separated(number, default_unit.nanos())
+            .map_err(|reason| InvalidDurationError::new(s, reason).at(offset(s, number)));
+    }
+    parse_duration(s)
+}
+
+/// Parses a duration whose unit is known out of band, such as a column
+/// documented as seconds, e.g. "30" or "1.5" as a number of `unit`.
+///
+/// A suffix is allowed as long as it names `unit`, in any spelling, so "30s"
+/// and "30 seconds" are read as seconds too. Any other unit is rejected with
+/// [`DurationParseError::UnexpectedUnit`] rather than silently converted.
+///
+/// # Arguments
+///
+/// * `s` - The string to parse
+/// * `unit` - The unit of the number
+///
+/// # Returns
+///
+/// A result containing the parsed duration, or an error if the string is not
+/// a valid duration in `unit`.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use serde_duration::{parse_duration_in, Unit};
+///
+/// assert_eq!(parse_duration_in("30", Unit::Seconds).unwrap(), Duration::from_secs(30));
+/// assert_eq!(parse_duration_in("30s", Unit::Seconds).unwrap(), Duration::from_secs(30));
+/// assert!(parse_duration_in("30m", Unit::Seconds).is_err());
+/// ```
+pub fn parse_duration_in(s: &str, unit: Unit) -> Result<Duration, InvalidDurationError> {
+    check_len(s, DEFAULT_MAX_LEN)?;
+    let trimmed = trim(s);
+    let unsigned = trimmed.strip_prefix('+').unwrap_or(trimmed);
+    let (number, suffix, rest) = split_token(unsigned);
+    if !number.is_empty() && suffix.is_empty() && rest.is_empty() {
+        return parse_separated(number, unit.nanos())
+            .map_err(|reason| InvalidDurationError::new(s, reason).at(offset(s, number)));
+    }
+
+    // Malformed input is left for `parse_duration` to report.
+    let mut rest = unsigned;
+    while let Ok(Some((token, tail))) = next_token(s, rest, rest.len() == unsigned.len()) {
+        if token.unit != unit {
+            let reason = DurationParseError::UnexpectedUnit(token.suffix.to_string(), unit);
+            return Err(InvalidDurationError::new(s, reason).at(offset(s, token.suffix)));
+        }
+        if tail.is_empty() {
+            break;
+        }
+        rest = tail;
     }
+    parse_duration(s)
 }
 
+/// Parses a duration like [`parse_duration`], using the given options, e.g. to
+/// change how long a month or a year is.
+///
+/// # Arguments
+///
+/// * `s` - The string to parse
+/// * `options` - The options to parse with
+///
+/// # Returns
+///
+/// A result containing the parsed duration, or an error if the string is not
+/// a valid duration.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use serde_duration::{parse_duration_with_options, ParseOptions};
+///
+/// let options = ParseOptions::new().secs_per_year(365 * 86400 + 21600);
+/// assert_eq!(
+///     parse_duration_with_options("1y", &options).unwrap(),
+///     Duration::from_secs(31_557_600)
+/// );
+/// ```
+pub fn parse_duration_with_options(
+    s: &str,
+    options: &ParseOptions,
+) -> Result<Duration, InvalidDurationError> {
+    str_to_duration_with(s, options)?.ok_or_else(|| InvalidDurationError::unrecognized(s))
+}
+
+/// Parses a duration in the given mode, e.g. to accept only the canonical
+/// form with [`ParseMode::Strict`]. [`ParseMode::Lenient`] parses exactly like
+/// [`parse_duration`].
+///
+/// # Arguments
+///
+/// * `s` - The string to parse
+/// * `mode` - How rigorously to check the string
+///
+/// # Returns
+///
+/// A result containing the parsed duration, or an error if the string is not
+/// a valid duration in that mode.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use serde_duration::{parse_duration_with_mode, ParseMode};
+///
+/// assert_eq!(
+///     parse_duration_with_mode("1h30m", ParseMode::Strict).unwrap(),
+///     Duration::from_secs(5400)
+/// );
+/// assert!(parse_duration_with_mode("1 hour 30 min", ParseMode::Lenient).is_ok());
+/// assert!(parse_duration_with_mode("1 hour 30 min", ParseMode::Strict).is_err());
+/// ```
+pub fn parse_duration_with_mode(
+    s: &str,
+    mode: ParseMode,
+) -> Result<Duration, InvalidDurationError> {
+    parse_duration_with_options(s, &ParseOptions::new().mode(mode))
+}
+
+/// Parses a duration like [`parse_duration`], but clamps durations too large
+/// for a `Duration` to `Duration::MAX` instead of failing, e.g. for
+/// effectively infinite timeouts.
+///
+/// # Arguments
+///
+/// * `s` - The string to parse
+///
+/// # Returns
+///
+/// A result containing the parsed duration, or an error if the string is not
+/// a valid duration for any reason other than its size.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use serde_duration::parse_duration_saturating;
+///
+/// assert_eq!(
+///     parse_duration_saturating("99999999999999999999h").unwrap(),
+///     Duration::MAX
+/// );
+/// assert_eq!(parse_duration_saturating("30s").unwrap(), Duration::from_secs(30));
+/// ```
+pub fn parse_duration_saturating(s: &str) -> Result<Duration, InvalidDurationError> {
+    parse_duration_with_options(s, &ParseOptions::new().saturating(true))
+}
+
+/// Parses a duration like [`parse_duration`], but tells strings that aren't
+/// durations at all apart from malformed ones, e.g. to probe whether a value
+/// is meant to be a duration.
+///
+/// # Arguments
+///
+/// * `s` - The string to parse
+///
+/// # Returns
+///
+/// `Ok(Some(duration))` for a valid duration, `Ok(None)` if the string is
+/// neither a bare "0" nor starts with a number followed by a known unit, such
+/// as "abc" or "30", and an error if it starts like a duration but is
+/// malformed, such as "1h 5x", or is empty.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use serde_duration::try_parse_duration;
+///
+/// assert_eq!(try_parse_duration("30s").unwrap(), Some(Duration::from_secs(30)));
+/// assert_eq!(try_parse_duration("hello").unwrap(), None);
+/// assert!(try_parse_duration("1h 5x").is_err());
+/// ```
+pub fn try_parse_duration(s: &str) -> Result<Option<Duration>, InvalidDurationError> {
+    str_to_duration(s)
+}
+
+/// Parses a duration like [`parse_duration`], returning just the structured
+/// [`DurationParseError`] on failure, for callers that match on the reason
+/// and have no use for the input or position.
+///
+/// Every way a string can fail to parse has its own variant, and values too
+/// large for a `Duration` are always reported as
+/// [`DurationParseError::Overflow`] rather than wrapping or saturating.
+///
+/// # Arguments
+///
+/// * `s` - The string to parse
+///
+/// # Returns
+///
+/// A result containing the parsed duration, or the reason the string is not a
+/// valid duration.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use serde_duration::{parse_duration_checked, DurationParseError};
+///
+/// assert_eq!(parse_duration_checked("90s"), Ok(Duration::from_secs(90)));
+/// assert_eq!(
+///     parse_duration_checked("99999999999999999999s"),
+///     Err(DurationParseError::Overflow)
+/// );
+/// ```
+pub fn parse_duration_checked(s: &str) -> Result<Duration, DurationParseError> {
+    parse_duration(s).map_err(|error| error.reason)
+}
+
+/// Parses a duration at compile time, for defining constants such as
+/// `const TIMEOUT: Duration = const_parse_duration("30s");`.
+///
+/// Only whole numbers with the short units "ns", "us", "µs", "ms", "s", "m",
+/// "h", "d" and "w" are understood, in any case. Compound durations such as
+/// "1h30m" and whitespace between tokens are fine, and a bare "0" is zero.
+/// Use [`parse_duration`] for anything else, such as fractions or
+/// spelled-out units.
+///
+/// # Arguments
+///
+/// * `s` - The string to parse
+///
+/// # Returns
+///
+/// The parsed duration.
+///
+/// # Panics
+///
+/// Panics if the string isn't a valid duration in this grammar or the
+/// duration doesn't fit in a `Duration`. In a `const` context this is a
+/// compile error rather than a runtime panic.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use serde_duration::const_parse_duration;
+///
+/// const TIMEOUT: Duration = const_parse_duration("30s");
+/// const INTERVAL: Duration = const_parse_duration("1h 30m");
+/// assert_eq!(TIMEOUT, Duration::from_secs(30));
+/// assert_eq!(INTERVAL, Duration::from_secs(5400));
+/// ```
+///
+/// ```compile_fail
+/// # use std::time::Duration;
+/// const TIMEOUT: Duration = serde_duration::const_parse_duration("30x");
+/// ```
+pub const fn const_parse_duration(s: &str) -> Duration {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    let mut total: u128 = 0;
+    let mut tokens = 0;
+    loop {
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        if i == bytes.len() {
+            break;
+        }
+
+        let number_start = i;
+        let mut value: u128 = 0;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            let digit = (bytes[i] - b'0') as u128;
+            value = match value.checked_mul(10) {
+                Some(value) => match value.checked_add(digit) {
+                    Some(value) => value,
+                    None => panic!("duration overflow"),
+                },
+                None => panic!("duration overflow"),
+            };
+            i += 1;
+        }
+        if i == number_start {
+            panic!("expected a whole number before each unit");
+        }
+        let number_end = i;
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        let unit_start = i;
+        while i < bytes.len() && !bytes[i].is_ascii_digit() && !bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        let unit_nanos = match const_unit_nanos(bytes, unit_start, i) {
+            Some(unit_nanos) => unit_nanos,
+            // A bare "0" is zero, as for `parse_duration`.
+            None if tokens == 0
+                && unit_start == bytes.len()
+                && number_end - number_start == 1
+                && bytes[number_start] == b'0' =>
+            {
+                return Duration::ZERO
+            }
+            None => panic!("missing or unknown unit"),
+        };
+        total = match value.checked_mul(unit_nanos) {
+            Some(nanos) => match total.checked_add(nanos) {
+                Some(total) => total,
+                None => panic!("duration overflow"),
+            },
+            None => panic!("duration overflow"),
+        };
+        tokens += 1;
+    }
+    if tokens == 0 {
+        panic!("empty duration");
+    }
+    let secs = total / 1_000_000_000;
+    if secs > u64::MAX as u128 {
+        panic!("duration overflow");
+    }
+    Duration::new(secs as u64, (total % 1_000_000_000) as u32)
+}
+
+/// The length in nanoseconds of the unit `bytes[start..end]`, for
+/// [`const_parse_duration`].
+const fn const_unit_nanos(bytes: &[u8], start: usize, end: usize) -> Option<u128> {
+    let unit = bytes.split_at(end).0.split_at(start).1;
+    if unit::eq_ignore_case(unit, "µs".as_bytes()) {
+        return Some(Unit::Micros.nanos());
+    }
+    let mut u = 0;
+    // Only the exact units, which come first.
+    while u < Unit::DESCENDING.len() {
+        let (_, suffix, nanos) = unit::UNITS[u];
+        if unit::eq_ignore_case(unit, suffix.as_bytes()) {
+            return Some(nanos);
+        }
+        u += 1;
+    }
+    None
+}
+
+/// Formats a duration as a string such as "90s" or "1h", outside of any serde
+/// context.
+///
+/// The output is identical to what [`serialize`] produces, so it can be used
+/// for log lines or user-facing messages that should match serialized configs.
+/// [`DurationFormat`] can be configured to produce other formats.
+///
+/// # Arguments
+///
+/// * `duration` - The duration to format
+///
+/// # Returns
+///
+/// The string representation of the duration.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// assert_eq!(serde_duration::format_duration(&Duration::from_secs(90)), "90s");
+/// assert_eq!(serde_duration::format_duration(&Duration::from_secs(3600)), "1h");
+/// ```
+pub fn format_duration(duration: &Duration) -> String {
+    duration_to_str(duration)
+}
+
+/// Writes a duration in the format of [`format_duration`] into `out`, such as
+/// a `String` that is reused for many durations, without allocating.
+///
+/// # Arguments
+///
+/// * `duration` - The duration to format
+/// * `out` - Where to write it
+///
+/// # Returns
+///
+/// The result of writing to `out`, which can only fail if `out` does.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use serde_duration::write_duration;
+///
+/// let mut line = String::from("timeout=");
+/// write_duration(&Duration::from_secs(90), &mut line).unwrap();
+/// assert_eq!(line, "timeout=90s");
+/// ```
+pub fn write_duration<W: fmt::Write>(duration: &Duration, out: &mut W) -> fmt::Result {
+    let nanos = duration.as_nanos();
+    if nanos == 0 {
+        return out.write_str("0s");
+    }
+    let unit = exact_unit(nanos);
+    write!(out, "{}{}", nanos / unit.nanos(), unit.suffix())
+}
+
+/// Formats `value` of a single `unit`, writing zero as "0s" whatever the
+/// unit, as every other formatter does.
+fn format_in_unit(value: u128, unit: Unit) -> String {
+    if value == 0 {
+        return "0s".to_string();
+    }
+    format!("{}{}", value, unit.suffix())
+}
+
+/// The largest unit up to weeks that `nanos` is a whole number of.
+fn exact_unit(nanos: u128) -> Unit {
+    // Nanoseconds always divide the duration, so a unit is always found.
+    Unit::DESCENDING
+        .into_iter()
+        .find(|unit| nanos.is_multiple_of(unit.nanos()))
+        .unwrap_or(Unit::Nanos)
+}
+
+/// An alias of [`format_duration`], with identical output, for callers who
+/// want the name to say that the result is exact.
+///
+/// Both write a duration in the largest unit up to weeks under which it is a
+/// whole number, e.g. 120 seconds as "2m" but 121 seconds as "121s", so the
+/// result always parses back to the same `Duration`.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use serde_duration::{format_duration, format_duration_exact};
+///
+/// let duration = Duration::from_secs(121);
+/// assert_eq!(format_duration_exact(&duration), format_duration(&duration));
+/// ```
+pub fn format_duration_exact(duration: &Duration) -> String {
+    duration_to_str(duration)
+}
+
+/// Formats a duration like [`format_duration`], without allocating for a few
+/// common values such as "0s", "30s", "1m" or "1h".
+///
+/// For hot paths such as logging that format the same few timeouts over and
+/// over. Common values are returned as `Cow::Borrowed` static strings and any
+/// other duration is formatted into a new `String`, so the result is always
+/// the same as [`format_duration`]'s.
+///
+/// # Arguments
+///
+/// * `duration` - The duration to format
+///
+/// # Returns
+///
+/// The string representation of the duration, borrowed if it is a common
+/// value.
+///
+/// # Examples
+///
+/// ```
+/// use std::borrow::Cow;
+/// use std::time::Duration;
+/// use serde_duration::format_duration_cow;
+///
+/// assert!(matches!(format_duration_cow(&Duration::from_secs(30)), Cow::Borrowed("30s")));
+/// assert_eq!(format_duration_cow(&Duration::from_secs(31)), "31s");
+/// ```
+pub fn format_duration_cow(duration: &Duration) -> Cow<'static, str> {
+    let s = match (duration.as_secs(), duration.subsec_nanos()) {
+        (0, 0) => "0s",
+        (0, 100_000_000) => "100ms",
+        (0, 250_000_000) => "250ms",
+        (0, 500_000_000) => "500ms",
+        (1, 0) => "1s",
+        (2, 0) => "2s",
+        (5, 0) => "5s",
+        (10, 0) => "10s",
+        (15, 0) => "15s",
+        (30, 0) => "30s",
+        (60, 0) => "1m",
+        (120, 0) => "2m",
+        (300, 0) => "5m",
+        (600, 0) => "10m",
+        (900, 0) => "15m",
+        (1800, 0) => "30m",
+        (3600, 0) => "1h",
+        (86_400, 0) => "1d",
+        (604_800, 0) => "1w",
+        _ => return Cow::Owned(duration_to_str(duration)),
+    };
+    Cow::Borrowed(s)
+}
+
+/// Rewrites a duration string in the canonical form that [`serialize`]
+/// writes, e.g. "60s" as "1m" or "1h 30 minutes" as "90m".
+///
+/// This is meant for tools that lint or `--fix` config files. The string is
+/// parsed like [`parse_duration`] parses it and then formatted with
+/// [`format_duration`], so the result always parses back to the same
+/// duration.
+///
+/// # Arguments
+///
+/// * `s` - The string to normalize
+///
+/// # Returns
+///
+/// A result containing the canonical string, or an error if `s` is not a
+/// valid duration.
+///
+/// # Examples
+///
+/// ```
+/// use serde_duration::normalize_duration_str;
+///
+/// assert_eq!(normalize_duration_str("120s").unwrap(), "2m");
+/// assert_eq!(normalize_duration_str("1h0m0s").unwrap(), "1h");
+/// assert!(normalize_duration_str("2 fortnights").is_err());
+/// ```
+pub fn normalize_duration_str(s: &str) -> Result<String, InvalidDurationError> {
+    parse_duration(s).map(|duration| duration_to_str(&duration))
+}
+
+/// Formats a duration as a whole number of the given unit, e.g. always in
+/// seconds for consistency in logs.
+///
+/// # Arguments
+///
+/// * `duration` - The duration to format
+/// * `unit` - The unit to express the duration in
+/// * `truncate` - Whether to drop any remainder that isn't a whole unit
+///
+/// # Returns
+///
+/// The formatted duration, or `None` if `truncate` is false and the duration
+/// isn't a whole number of `unit`. A duration that is zero, or rounds to
+/// zero of `unit`, is always formatted as "0s", whatever the unit.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use serde_duration::{format_duration_as, Unit};
+///
+/// let duration = Duration::from_secs(5400);
+/// assert_eq!(format_duration_as(&duration, Unit::Seconds, false).unwrap(), "5400s");
+/// assert_eq!(format_duration_as(&duration, Unit::Minutes, false).unwrap(), "90m");
+/// assert_eq!(format_duration_as(&duration, Unit::Hours, false), None);
+/// assert_eq!(format_duration_as(&duration, Unit::Hours, true).unwrap(), "1h");
+/// ```
+pub fn format_duration_as(duration: &Duration, unit: Unit, truncate: bool) -> Option<String> {
+    format_duration_as_rounded(duration, unit, truncate.then_some(RoundingMode::Truncate))
+}
+
+/// Formats a duration as a whole number of the given unit like
+/// [`format_duration_as`], choosing what happens to a remainder that isn't a
+/// whole unit.
+///
+/// With `None` such durations aren't formatted at all, and otherwise they are
+/// rounded in the given mode, so 5400 seconds in hours are "1h" when truncated
+/// and "2h" when rounded.
+///
+/// # Arguments
+///
+/// * `duration` - The duration to format
+/// * `unit` - The unit to express the duration in
+/// * `rounding` - How to round a remainder, or `None` to reject it
+///
+/// # Returns
+///
+/// The formatted duration, or `None` if `rounding` is `None` and the duration
+/// isn't a whole number of `unit`. A duration that is zero, or rounds to
+/// zero of `unit`, is always formatted as "0s", whatever the unit.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use serde_duration::{format_duration_as_rounded, RoundingMode, Unit};
+///
+/// let duration = Duration::from_secs(5400);
+/// assert_eq!(format_duration_as_rounded(&duration, Unit::Hours, None), None);
+/// assert_eq!(
+///     format_duration_as_rounded(&duration, Unit::Hours, Some(RoundingMode::Truncate)).unwrap(),
+///     "1h"
+/// );
+/// assert_eq!(
+///     format_duration_as_rounded(&duration, Unit::Hours, Some(RoundingMode::Round)).unwrap(),
+///     "2h"
+/// );
+/// ```
+pub fn format_duration_as_rounded(
+    duration: &Duration,
+    unit: Unit,
+    rounding: Option<RoundingMode>,
+) -> Option<String> {
+    let nanos = duration.as_nanos();
+    let value = match rounding {
+        Some(rounding) => rounding.divide(nanos, unit.nanos()),
+        None if nanos.is_multiple_of(unit.nanos()) => nanos / unit.nanos(),
+        None => return None,
+    };
+    Some(format_in_unit(value, unit))
+}
+
+/// Formats a duration in a single unit, rounding it as requested.
+///
+/// The largest unit that the duration is at least one of is used, so 90
+/// seconds are expressed in minutes and 59 seconds in seconds. This is meant
+/// for display, where a short approximate value is more useful than an
+/// exact one; use [`format_duration`] when the result has to round-trip. A
+/// zero duration is formatted as "0s".
+///
+/// # Arguments
+///
+/// * `duration` - The duration to format
+/// * `mode` - How to round a duration that isn't a whole number of the unit
+///
+/// # Returns
+///
+/// The formatted, possibly rounded, duration.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use serde_duration::{format_duration_rounded, RoundingMode};
+///
+/// let duration = Duration::from_secs(90);
+/// assert_eq!(format_duration_rounded(&duration, RoundingMode::Truncate), "1m");
+/// assert_eq!(format_duration_rounded(&duration, RoundingMode::Round), "2m");
+/// assert_eq!(format_duration_rounded(&duration, RoundingMode::Ceil), "2m");
+/// ```
+pub fn format_duration_rounded(duration: &Duration, mode: RoundingMode) -> String {
+    let nanos = duration.as_nanos();
+    let Some(mut index) = Unit::DESCENDING
+        .iter()
+        .position(|unit| nanos >= unit.nanos())
+    else {
+        return "0s".to_string();
+    };
+    let mut value = mode.divide(nanos, Unit::DESCENDING[index].nanos());
+    // Rounding up can fill the next larger unit, as 59.5 seconds round to
+    // "60s", which is then written as "1m" instead.
+    while index > 0
+        && value * Unit::DESCENDING[index].nanos() >= Unit::DESCENDING[index - 1].nanos()
+    {
+        index -= 1;
+        value = mode.divide(nanos, Unit::DESCENDING[index].nanos());
+    }
+    format!("{}{}", value, Unit::DESCENDING[index].suffix())
+}
+
+/// Formats a duration losslessly as a compound string such as "1h1m1s",
+/// listing every non-zero unit from weeks down to nanoseconds.
+///
+/// Unlike [`serialize`], which writes a single unit, the result always parses
+/// back to exactly the same `Duration`. A zero duration is formatted as "0s".
+///
+/// # Arguments
+///
+/// * `duration` - The duration to format
+///
+/// # Returns
+///
+/// The compound string representation of the duration.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// assert_eq!(serde_duration::format_duration_compound(&Duration::from_secs(90)), "1m30s");
+/// assert_eq!(serde_duration::format_duration_compound(&Duration::from_secs(3661)), "1h1m1s");
+/// ```
+pub fn format_duration_compound(duration: &Duration) -> String {
+    DurationFormat::new().compound(true).format(duration)
+}
+
+/// Formats a duration as English text for display, such as
+/// "1 hour 30 minutes", using at most `max_components` units.
+///
+/// The units are the most significant ones, starting at the largest non-zero
+/// unit from weeks down to nanoseconds, and the rest is truncated. Units that
+/// are zero are left out but still count, so with two components 1h0m15s is
+/// just "1 hour". This is meant for people, not for parsing back; use
+/// [`format_duration_compound`] for that. A zero duration is "0 seconds".
+///
+/// # Arguments
+///
+/// * `duration` - The duration to format
+/// * `max_components` - How many units to show at most; at least one always
+///   is
+///
+/// # Returns
+///
+/// The human-readable representation of the duration.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use serde_duration::format_duration_pretty;
+///
+/// let duration = Duration::from_secs(5415);
+/// assert_eq!(format_duration_pretty(&duration, 3), "1 hour 30 minutes 15 seconds");
+/// assert_eq!(format_duration_pretty(&duration, 2), "1 hour 30 minutes");
+/// assert_eq!(format_duration_pretty(&duration, 1), "1 hour");
+/// ```
+pub fn format_duration_pretty(duration: &Duration, max_components: usize) -> String {
+    let mut nanos = duration.as_nanos();
+    let Some(first) = Unit::DESCENDING
+        .iter()
+        .position(|unit| nanos >= unit.nanos())
+    else {
+        return "0 seconds".to_string();
+    };
+
+    let mut s = String::new();
+    for unit in Unit::DESCENDING[first..].iter().take(max_components.max(1)) {
+        let value = nanos / unit.nanos();
+        nanos %= unit.nanos();
+        if value == 0 {
+            continue;
+        }
+        if !s.is_empty() {
+            s.push(' ');
+        }
+        s.push_str(&format!("{} {}", value, unit.name()));
+        if value != 1 {
+            s.push('s');
+        }
+    }
+    s
+}
+
+/// Whether `s` is one of the spellings of an infinite duration accepted by the
+/// [`infinite`](crate::infinite) module, ignoring case.
+fn is_infinite(s: &str) -> bool {
+    let s = trim(s);
+    s.eq_ignore_ascii_case("inf") || s.eq_ignore_ascii_case("infinite")
+}
+
+/// Strips the leading and trailing ASCII whitespace that config files tend to
+/// leave around values. Whitespace inside the duration is not touched.
+fn trim(s: &str) -> &str {
+    s.trim_matches(|c: char| c.is_ascii_whitespace())
+}
+
+/// Splits an optional leading '-' or '+' off a duration string, for the
+/// signed duration types that support negative values.
+fn split_sign(s: &str) -> (bool, &str) {
+    let s = trim(s);
+    if let Some(magnitude) = s.strip_prefix('-') {
+        (true, magnitude)
+    } else {
+        (false, s.strip_prefix('+').unwrap_or(s))
+    }
+}
+
+/// Parses a duration that may be negative, returning whether it was negative
+/// and its magnitude.
+fn parse_signed(s: &str) -> Result<(bool, Duration), InvalidDurationError> {
+    let (negative, magnitude) = split_sign(s);
+    let unsigned = trim(magnitude);
+    if unsigned.starts_with(['-', '+']) {
+        let error = InvalidDurationError::new(s, DurationParseError::RepeatedSign);
+        return Err(error.at(offset(s, unsigned)));
+    }
+    let magnitude = parse_duration(magnitude).map_err(|e| InvalidDurationError {
+        input: s.to_string(),
+        position: e.position.map(|position| position + offset(s, magnitude)),
+        ..e
+    })?;
+    Ok((negative, magnitude))
+}
+
+/// Formats a duration that may be negative, using the same format as
+/// [`format_duration`] prefixed with '-' when negative.
+fn format_signed(negative: bool, magnitude: &Duration) -> String {
+    if negative && !magnitude.is_zero() {
+        format!("-{}", duration_to_str(magnitude))
+    } else {
+        duration_to_str(magnitude)
+    }
+}
+
+/// Splits the leading `(number, unit)` token off `s`, returning the number,
+/// the unit and whatever follows them. Whitespace between the number and the
+/// unit, and after the unit, is skipped.
+///
+/// The number may include an exponent, as in "1e3" or "1.5e-3", which is
+/// only accepted by [`parse_number`] in scientific mode.
+///
+/// The number is all ASCII and the rest is only ever split at positions
+/// returned by `str::find`, which are char boundaries, so multi-byte
+/// characters such as "µ" or "€" can't cause a panic.
+fn split_token(s: &str) -> (&str, &str, &str) {
+    let is_whitespace = |c: char| c.is_ascii_whitespace();
+    let bytes = s.as_bytes();
+    let mut number_end = 0;
+    while number_end < bytes.len() {
+        match bytes[number_end] {
+            b'0'..=b'9' | b'.' | b',' | b'_' => number_end += 1,
+            // An exponent must directly follow a digit and have digits of
+            // its own, so "1e" is still the number "1" with the unit "e".
+            b'e' | b'E' if number_end > 0 && bytes[number_end - 1].is_ascii_digit() => {
+                let sign = usize::from(matches!(bytes.get(number_end + 1), Some(b'+' | b'-')));
+                if !bytes
+                    .get(number_end + 1 + sign)
+                    .is_some_and(u8::is_ascii_digit)
+                {
+                    break;
+                }
+                number_end += 2 + sign;
+            }
+            _ => break,
+        }
+    }
+    let (number, rest) = s.split_at(number_end);
+    let rest = rest.trim_start_matches(is_whitespace);
+    let unit_end = rest
+        .find(|c: char| c.is_ascii_digit() || is_whitespace(c))
+        .unwrap_or(rest.len());
+    let (unit, rest) = rest.split_at(unit_end);
+    (number, unit, rest.trim_start_matches(is_whitespace))
+}
+
+/// Parses a duration made of one or more `(number, unit)` tokens, such as
+/// "30s" or "1h30m15s", summing the tokens. Repeated units are summed as
+/// well, so "1h1h" is two hours.
+///
+/// Whitespace around the input, between a number and its unit, and between
+/// tokens is ignored, so "1 h 30 m" is the same as "1h30m". Whitespace inside
+/// a number or a unit is still an error.
+///
+/// Returns `Ok(None)` if the input does not start with a number followed by a
+/// known unit, i.e. it doesn't look like a duration at all, and an error if it
+/// starts like one but is malformed later on.
 fn str_to_duration(s: &str) -> Result<Option<Duration>, InvalidDurationError> {
-    let multiplier = match s.chars().last() {
-        Some('s') => Duration::from_secs(1),
-        Some('m') => Duration::from_secs(60),
-        Some('h') => Duration::from_secs(3600),
-        _ => return Ok(None),
+    str_to_duration_with(s, &ParseOptions::new())
+}
+
+fn str_to_duration_with(
+    s: &str,
+    options: &ParseOptions,
+) -> Result<Option<Duration>, InvalidDurationError> {
+    check_len(s, options.max_len)?;
+    // Zero is zero in any unit, and a bare "0" commonly means "disabled".
+    let bare = match options.mode {
+        ParseMode::Strict => s,
+        ParseMode::Lenient => trim(s).strip_prefix('+').unwrap_or(trim(s)),
     };
-    let value = s[..s.len() - 1]
-        .parse::<u64>()
-        .map_err(|_| InvalidDurationError)?;
-    Ok(Some(Duration::from_secs(multiplier.as_secs() * value)))
+    if bare == "0" {
+        return Ok(Some(Duration::ZERO));
+    }
+    if options.mode == ParseMode::Strict {
+        check_canonical(s).map_err(|(reason, at)| InvalidDurationError::new(s, reason).at(at))?;
+        let options = &options.strict(true);
+        return parse_tokens(s, options)
+            .map_err(|(reason, at)| InvalidDurationError::new(s, reason).at(at));
+    }
+    let trimmed = trim(s);
+    parse_tokens(trimmed, options)
+        .map_err(|(reason, at)| InvalidDurationError::new(s, reason).at(offset(s, trimmed) + at))
 }
 
-fn duration_to_str(duration: &Duration) -> String {
-    let seconds = duration.as_secs();
-    if seconds >= 3600 {
-        format!("{}h", seconds / 3600)
-    } else if seconds >= 60 {
-        format!("{}m", seconds / 60)
+/// Rejects anything in `s` but whole numbers directly followed by canonical
+/// units, for [`ParseMode::Strict`]. Unknown units and the order of units are
+/// left to [`parse_tokens`].
+fn check_canonical(s: &str) -> Result<(), TokenError> {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        let reason = match bytes[i] {
+            b'0'..=b'9' => {
+                i += 1;
+                continue;
+            }
+            b if b.is_ascii_whitespace() => {
+                DurationParseError::InvalidFormat("whitespace is not allowed in strict mode")
+            }
+            b'+' => DurationParseError::InvalidFormat("'+' is not allowed in strict mode"),
+            b'_' => {
+                DurationParseError::InvalidFormat("digit separators are not allowed in strict mode")
+            }
+            b'.' | b',' => {
+                DurationParseError::InvalidFormat("fractions are not allowed in strict mode")
+            }
+            _ => {
+                let (_, suffix, _) = split_token(&s[i..]);
+                match Unit::from_alias(suffix) {
+                    Some(unit) if unit.suffix() != suffix => {
+                        DurationParseError::NonCanonicalUnit(suffix.to_string())
+                    }
+                    // The suffix can't be empty, since it starts at a byte
+                    // that is neither a digit nor whitespace.
+                    _ => {
+                        i += suffix.len();
+                        continue;
+                    }
+                }
+            }
+        };
+        return Err((reason, i));
+    }
+    Ok(())
+}
+
+/// The longest input, in bytes, that is parsed by default. Longer strings are
+/// rejected up front, so that untrusted input such as a million digit number
+/// can't make the parser do needless work.
+const DEFAULT_MAX_LEN: usize = 256;
+
+/// How many bytes of an input that is too long are kept in the error.
+const TOO_LONG_PREFIX_LEN: usize = 32;
+
+/// Rejects inputs longer than `max_len` bytes.
+fn check_len(s: &str, max_len: usize) -> Result<(), InvalidDurationError> {
+    if s.len() > max_len {
+        return Err(InvalidDurationError::too_long(s, max_len));
+    }
+    Ok(())
+}
+
+/// A reason a duration was rejected, with the byte offset at which the
+/// problem was found.
+type TokenError = (DurationParseError, usize);
+
+fn parse_tokens(s: &str, options: &ParseOptions) -> Result<Option<Duration>, TokenError> {
+    let at = |part: &str| offset(s, part);
+    let unsigned = strip_plus(s)?;
+
+    let mut total = Duration::ZERO;
+    let mut rest = unsigned;
+    let mut previous_nanos = None;
+    loop {
+        let is_first = rest.len() == unsigned.len();
+        let Some((token, tail)) = next_token(s, rest, is_first)? else {
+            return Ok(None);
+        };
+        let Token {
+            number,
+            suffix,
+            unit,
+        } = token;
+        let unit_nanos = options.unit_nanos(unit);
+        if options.strict {
+            match previous_nanos {
+                Some(previous) if previous == unit_nanos => {
+                    return Err((
+                        DurationParseError::DuplicateUnit(suffix.to_string()),
+                        at(suffix),
+                    ))
+                }
+                Some(previous) if previous < unit_nanos => {
+                    return Err((
+                        DurationParseError::MisorderedUnit(suffix.to_string()),
+                        at(suffix),
+                    ))
+                }
+                _ => previous_nanos = Some(unit_nanos),
+            }
+        }
+        total = match parse_number(number, unit_nanos, options)
+            .and_then(|value| total.checked_add(value).ok_or(DurationParseError::Overflow))
+        {
+            Ok(total) => total,
+            // Keep going, so that a later malformed part is still reported.
+            Err(DurationParseError::Overflow) if options.saturating => Duration::MAX,
+            Err(reason) => return Err((reason, at(number))),
+        };
+
+        if tail.is_empty() {
+            return Ok(Some(total));
+        }
+        rest = tail;
+    }
+}
+
+/// Checks that the trimmed duration `s` isn't empty or negative, and strips
+/// an optional leading '+'.
+fn strip_plus(s: &str) -> Result<&str, TokenError> {
+    if s.is_empty() {
+        return Err((DurationParseError::EmptyInput, 0));
+    }
+    if s.starts_with('-') {
+        return Err((DurationParseError::Negative, 0));
+    }
+    match s.strip_prefix('+') {
+        Some("") => Err((
+            DurationParseError::InvalidFormat("missing duration after '+'"),
+            0,
+        )),
+        Some(unsigned) => Ok(unsigned),
+        None => Ok(s),
+    }
+}
+
+/// A number and the unit following it, as in "30s" or "1.5 hours".
+struct Token<'a> {
+    number: &'a str,
+    /// The unit as written
+    suffix: &'a str,
+    unit: Unit,
+}
+
+/// Splits the next token off `rest`, the remainder of the duration `s`,
+/// returning it and whatever follows it. The number is left unparsed.
+///
+/// Returns `Ok(None)` if `is_first` and the token has no known unit, since the
+/// input then doesn't look like a duration at all. Error offsets are relative
+/// to `s`.
+fn next_token<'a>(
+    s: &str,
+    rest: &'a str,
+    is_first: bool,
+) -> Result<Option<(Token<'a>, &'a str)>, TokenError> {
+    let (number, suffix, tail) = split_token(rest);
+    let unit = match Unit::from_alias(suffix) {
+        Some(unit) => unit,
+        None if is_first => return Ok(None),
+        None if suffix.is_empty() => {
+            return Err((DurationParseError::MissingUnit, offset(s, suffix)))
+        }
+        None => {
+            return Err((
+                DurationParseError::UnknownUnit(suffix.to_string()),
+                offset(s, suffix),
+            ))
+        }
+    };
+    if number.is_empty() {
+        return Err((
+            DurationParseError::MissingValue(suffix.to_string()),
+            offset(s, suffix),
+        ));
+    }
+    let token = Token {
+        number,
+        suffix,
+        unit,
+    };
+    Ok(Some((token, tail)))
+}
+
+/// The byte offset of `part`, which must be a subslice of `s`, within `s`.
+fn offset(s: &str, part: &str) -> usize {
+    part.as_ptr() as usize - s.as_ptr() as usize
+}
+
+/// Parses a number with [`parse_separated`], or [`parse_scientific`] if it has
+/// an exponent, first turning a decimal comma into a dot if the options allow
+/// it. Errors still show the number as it was written.
+fn parse_number(
+    number: &str,
+    unit_nanos: u128,
+    options: &ParseOptions,
+) -> Result<Duration, DurationParseError> {
+    let normalized;
+    let mut value = number;
+    if options.decimal_comma && number.contains(',') {
+        normalized = number.replace(',', ".");
+        value = &normalized;
+    }
+    let parsed = if options.scientific && value.contains(['e', 'E']) {
+        parse_scientific(value, unit_nanos)
     } else {
-        format!("{}s", seconds)
+        parse_separated(value, unit_nanos)
+    };
+    parsed.map_err(|reason| match reason {
+        DurationParseError::InvalidNumber(_) => {
+            DurationParseError::InvalidNumber(number.to_string())
+        }
+        DurationParseError::MisplacedSeparator(_) => {
+            DurationParseError::MisplacedSeparator(number.to_string())
+        }
+        reason => reason,
+    })
+}
+
+/// Parses a number in scientific notation, such as "1e3" or "1.5e-2".
+///
+/// Rather than going through `f64`, the decimal point of the mantissa is
+/// moved by the exponent and the result parsed with [`parse_scaled`], so
+/// "1.5e2ms" is exactly 150 milliseconds.
+fn parse_scientific(number: &str, unit_nanos: u128) -> Result<Duration, DurationParseError> {
+    let invalid_number = || DurationParseError::InvalidNumber(number.to_string());
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    let (mantissa, exponent) = number.split_once(['e', 'E']).ok_or_else(invalid_number)?;
+    let (whole, fraction) = match mantissa.split_once('.') {
+        Some((_, "")) => return Err(invalid_number()),
+        Some((whole, fraction)) => (whole, fraction),
+        None => (mantissa, ""),
+    };
+    let exponent_digits = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+    if whole.is_empty()
+        || !is_digits(whole)
+        || !is_digits(fraction)
+        || exponent_digits.is_empty()
+        || !is_digits(exponent_digits)
+    {
+        return Err(invalid_number());
     }
+
+    let all_digits = format!("{}{}", whole, fraction);
+    let digits = all_digits.trim_start_matches('0');
+    if digits.is_empty() {
+        return Ok(Duration::ZERO);
+    }
+    // The exponent is all digits, so failing to parse it means it's huge.
+    let exponent = match exponent.parse::<i32>() {
+        Ok(exponent) => i64::from(exponent),
+        Err(_) if exponent.starts_with('-') => return Ok(Duration::ZERO),
+        Err(_) => return Err(DurationParseError::Overflow),
+    };
+    // Where the decimal point ends up, counted from the start of `digits`.
+    let point = whole.len() as i64 - (all_digits.len() - digits.len()) as i64 + exponent;
+    // Even a single digit followed by 40 zeros overflows in any unit, and
+    // parse_scaled ignores fraction digits after the 19th.
+    if point > 40 {
+        return Err(DurationParseError::Overflow);
+    }
+    if point < -19 {
+        return Ok(Duration::ZERO);
+    }
+    let shifted = if point <= 0 {
+        format!("0.{}{}", "0".repeat(point.unsigned_abs() as usize), digits)
+    } else if point as usize >= digits.len() {
+        format!("{}{}", digits, "0".repeat(point as usize - digits.len()))
+    } else {
+        let (whole, fraction) = digits.split_at(point as usize);
+        format!("{}.{}", whole, fraction)
+    };
+    parse_scaled(&shifted, unit_nanos)
+}
+
+/// Like [`parse_value`], but also accepts '_' separators between digits, as in
+/// "1_000". Separators anywhere else, e.g. "_1", "1_" or "1__0", are rejected.
+fn parse_separated(number: &str, unit_nanos: u128) -> Result<Duration, DurationParseError> {
+    if !number.contains('_') {
+        return parse_scaled(number, unit_nanos);
+    }
+    let bytes = number.as_bytes();
+    let misplaced = bytes.iter().enumerate().any(|(i, &b)| {
+        b == b'_'
+            && !(i > 0
+                && bytes[i - 1].is_ascii_digit()
+                && bytes.get(i + 1).is_some_and(u8::is_ascii_digit))
+    });
+    if misplaced {
+        return Err(DurationParseError::MisplacedSeparator(number.to_string()));
+    }
+    parse_scaled(&number.replace('_', ""), unit_nanos)
+}
+
+/// Parses a number such as "30" or "1.5" and converts it to a `Duration` using
+/// the given unit.
+///
+/// The whole and fractional parts are parsed separately so that e.g. "1.5h" is
+/// exactly 5400 seconds rather than whatever floating point arithmetic would
+/// produce. Fractions finer than a nanosecond are truncated.
+fn parse_value(number: &str, unit: Unit) -> Result<Duration, DurationParseError> {
+    parse_scaled(number, unit.nanos())
+}
+
+/// Like [`parse_value`], with the unit given as its length in nanoseconds.
+fn parse_scaled(number: &str, unit_nanos: u128) -> Result<Duration, DurationParseError> {
+    let invalid_number = || DurationParseError::InvalidNumber(number.to_string());
+    let (whole, fraction) = match number.split_once('.') {
+        Some((whole, fraction)) => (whole, Some(fraction)),
+        None => (number, None),
+    };
+    // Accumulate in u128 nanoseconds so that values too large for a u64, like
+    // "20000000000000000000ns", still parse as long as the `Duration` fits.
+    let whole = match whole.parse::<u128>() {
+        Ok(whole) => whole,
+        Err(_) if !whole.is_empty() && whole.bytes().all(|b| b.is_ascii_digit()) => {
+            return Err(DurationParseError::Overflow)
+        }
+        Err(_) => return Err(invalid_number()),
+    };
+    let mut nanos = whole
+        .checked_mul(unit_nanos)
+        .ok_or(DurationParseError::Overflow)?;
+
+    if let Some(fraction) = fraction {
+        if fraction.is_empty() || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid_number());
+        }
+        // 19 digits are plenty to resolve a nanosecond even for the largest
+        // built-in unit, and keep the arithmetic below within u128 for all
+        // but absurdly long custom month or year lengths.
+        let fraction = &fraction[..fraction.len().min(19)];
+        let numerator = fraction.parse::<u128>().map_err(|_| invalid_number())?;
+        let denominator = 10u128.pow(fraction.len() as u32);
+        let fraction_nanos = numerator
+            .checked_mul(unit_nanos)
+            .ok_or(DurationParseError::Overflow)?;
+        nanos = nanos
+            .checked_add(fraction_nanos / denominator)
+            .ok_or(DurationParseError::Overflow)?;
+    }
+
+    nanos_to_duration(nanos).ok_or(DurationParseError::Overflow)
+}
+
+/// Converts a number of nanoseconds to a `Duration`, if it fits.
+fn nanos_to_duration(nanos: u128) -> Option<Duration> {
+    let secs = u64::try_from(nanos / 1_000_000_000).ok()?;
+    Some(Duration::new(secs, (nanos % 1_000_000_000) as u32))
+}
+
+/// Formats `value / 10^digits` with the fraction's trailing zeros removed,
+/// e.g. 1500 with 3 digits is "1.5".
+fn write_fraction(s: &mut String, value: u128, digits: usize) {
+    use core::fmt::Write;
+
+    let scale = 10u128.pow(digits as u32);
+    let (whole, fraction) = (value / scale, value % scale);
+    // Writing to a String can't fail
+    let _ = write!(s, "{}", whole);
+    if fraction > 0 {
+        let fraction = format!("{:0width$}", fraction, width = digits);
+        let _ = write!(s, ".{}", fraction.trim_end_matches('0'));
+    }
+}
+
+fn duration_to_str(duration: &Duration) -> String {
+    let mut s = String::new();
+    write_duration(duration, &mut s).expect("writing to a String never fails");
+    s
 }
@@ -0,0 +1,107 @@
+//! Serialization of maps with `Duration` values, such as
+//! `HashMap<String, Duration>`.
+//!
+//! Each value uses the same string format as the crate root, so a map is
+//! serialized as e.g. `{"connect":"5s","read":"30s"}`. Any map type that can
+//! be built up one entry at a time works, including `HashMap` and `BTreeMap`,
+//! with any key type that serde supports and that can be displayed.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::collections::HashMap;
+//! use std::time::Duration;
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct MyConfig {
+//!     #[serde(with = "serde_duration::map_values")]
+//!     timeouts: HashMap<String, Duration>,
+//! }
+//!
+//! let config: MyConfig =
+//!     serde_json::from_str(r#"{"timeouts": {"connect": "5s", "read": "30s"}}"#).unwrap();
+//! assert_eq!(config.timeouts["connect"], Duration::from_secs(5));
+//! assert_eq!(config.timeouts["read"], Duration::from_secs(30));
+//! ```
+
+use alloc::format;
+use core::fmt;
+use core::marker::PhantomData;
+use core::time::Duration;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{DeserializeDuration, SerializeDuration};
+
+/// Serializes a map of durations as a map of duration strings.
+///
+/// # Arguments
+///
+/// * `map` - The map to serialize
+/// * `serializer` - The serde serializer
+///
+/// # Returns
+///
+/// A result containing the serialized map if serialization was successful,
+/// or an error if serialization failed.
+pub fn serialize<'a, M, K, S>(map: &'a M, serializer: S) -> Result<S::Ok, S::Error>
+where
+    &'a M: IntoIterator<Item = (&'a K, &'a Duration)>,
+    K: Serialize + 'a,
+    S: Serializer,
+{
+    serializer.collect_map(
+        map.into_iter()
+            .map(|(key, duration)| (key, SerializeDuration(duration))),
+    )
+}
+
+/// Deserializes a map of durations from a map of duration strings.
+///
+/// Fails on the first value that is not a valid duration, naming its key in
+/// the error.
+///
+/// # Arguments
+///
+/// * `deserializer` - The serde deserializer
+///
+/// # Returns
+///
+/// A result containing the deserialized map if deserialization was
+/// successful, or an error if deserialization failed.
+pub fn deserialize<'de, M, K, D>(deserializer: D) -> Result<M, D::Error>
+where
+    M: Default + Extend<(K, Duration)>,
+    K: Deserialize<'de> + fmt::Display,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_map(MapVisitor(PhantomData))
+}
+
+struct MapVisitor<M, K>(PhantomData<fn() -> (M, K)>);
+
+impl<'de, M, K> de::Visitor<'de> for MapVisitor<M, K>
+where
+    M: Default + Extend<(K, Duration)>,
+    K: Deserialize<'de> + fmt::Display,
+{
+    type Value = M;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a map of durations")
+    }
+
+    fn visit_map<A>(self, mut access: A) -> Result<M, A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let mut map = M::default();
+        while let Some(key) = access.next_key::<K>()? {
+            let DeserializeDuration(duration) = access
+                .next_value()
+                .map_err(|e| de::Error::custom(format!("at key \"{}\": {}", key, e)))?;
+            map.extend(core::iter::once((key, duration)));
+        }
+        Ok(map)
+    }
+}
@@ -0,0 +1,73 @@
+//! Serialization of `Duration` values as a number of milliseconds.
+//!
+//! Durations are always written as a number of milliseconds, e.g. "1500ms"
+//! rather than "1.5s", so that latency-style values are never collapsed into
+//! larger units. Precision finer than a millisecond is kept as a decimal
+//! fraction, e.g. "0.25ms". Deserialization accepts every unit the crate root
+//! does, so existing values such as "1s" still load.
+//!
+//! # Examples
+//!
+//! ```
+//! use core::time::Duration;
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct MyConfig {
+//!     #[serde(with = "serde_duration::millis")]
+//!     timeout: Duration,
+//! }
+//!
+//! let config = MyConfig { timeout: Duration::from_millis(1500) };
+//! assert_eq!(serde_json::to_string(&config).unwrap(), r#"{"timeout":"1500ms"}"#);
+//! ```
+
+use alloc::string::String;
+use core::time::Duration;
+use serde::{Deserializer, Serializer};
+
+use crate::write_fraction;
+
+/// Serializes a duration as a number of milliseconds such as "1500ms".
+///
+/// A zero duration is written as the canonical "0s" like everywhere else.
+///
+/// # Arguments
+///
+/// * `duration` - The duration to serialize
+/// * `serializer` - The serde serializer
+///
+/// # Returns
+///
+/// A result containing the serialized string if serialization was successful,
+/// or an error if serialization failed.
+pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if duration.is_zero() {
+        return serializer.serialize_str("0s");
+    }
+    let mut s = String::new();
+    write_fraction(&mut s, duration.as_nanos(), 6);
+    s.push_str("ms");
+    serializer.serialize_str(&s)
+}
+
+/// Deserializes a duration from a string, accepting everything that
+/// [`crate::deserialize`] accepts.
+///
+/// # Arguments
+///
+/// * `deserializer` - The serde deserializer
+///
+/// # Returns
+///
+/// A result containing the deserialized duration if deserialization was successful,
+/// or an error if deserialization failed.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    crate::deserialize(deserializer)
+}
@@ -0,0 +1,69 @@
+//! Serialization of `Duration` values where `null` means no time at all.
+//!
+//! This works like the crate root, except that `null` deserializes to
+//! `Duration::ZERO` instead of being an error. It suits configs that write
+//! `null` for "no delay", without turning the field into an `Option` that is
+//! unwrapped everywhere. Unlike the [`option`](crate::option) module, zero is
+//! serialized as "0s" rather than `null`. Add `#[serde(default)]` to the field
+//! as well if it may be missing from the input entirely.
+//!
+//! # Examples
+//!
+//! ```
+//! use core::time::Duration;
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct MyConfig {
+//!     #[serde(with = "serde_duration::null_as_zero")]
+//!     delay: Duration,
+//! }
+//!
+//! let config: MyConfig = serde_json::from_str(r#"{"delay": null}"#).unwrap();
+//! assert_eq!(config.delay, Duration::ZERO);
+//!
+//! let config: MyConfig = serde_json::from_str(r#"{"delay": "30s"}"#).unwrap();
+//! assert_eq!(config.delay, Duration::from_secs(30));
+//! ```
+
+use core::time::Duration;
+use serde::{Deserialize, Deserializer, Serializer};
+
+use crate::DeserializeDuration;
+
+/// Serializes a duration like [`crate::serialize`].
+///
+/// # Arguments
+///
+/// * `duration` - The duration to serialize
+/// * `serializer` - The serde serializer
+///
+/// # Returns
+///
+/// A result containing the serialized string if serialization was successful,
+/// or an error if serialization failed.
+pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    crate::serialize(duration, serializer)
+}
+
+/// Deserializes a duration, mapping `null` to `Duration::ZERO` and anything
+/// else through [`crate::deserialize`].
+///
+/// # Arguments
+///
+/// * `deserializer` - The serde deserializer
+///
+/// # Returns
+///
+/// A result containing the deserialized duration if deserialization was
+/// successful, or an error if deserialization failed.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let duration = Option::<DeserializeDuration>::deserialize(deserializer)?;
+    Ok(duration.map_or(Duration::ZERO, |DeserializeDuration(duration)| duration))
+}
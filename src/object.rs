@@ -0,0 +1,139 @@
+//! Serialization of `Duration` values as objects such as
+//! `{"hours": 1, "minutes": 30}`.
+//!
+//! The object may have any of the keys `days`, `hours`, `minutes`, `seconds`,
+//! `millis`, `micros` and `nanos`, each holding a whole number, and their sum
+//! is the duration. Missing keys count as zero, and unknown or repeated keys
+//! are rejected. Serialization writes the minimal object, leaving out zero
+//! components, so zero is `{}`.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::time::Duration;
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct MyConfig {
+//!     #[serde(with = "serde_duration::object")]
+//!     timeout: Duration,
+//! }
+//!
+//! let config: MyConfig = serde_json::from_str(r#"{"timeout": {"hours": 1, "minutes": 30}}"#).unwrap();
+//! assert_eq!(config.timeout, Duration::from_secs(5400));
+//! assert_eq!(
+//!     serde_json::to_string(&config).unwrap(),
+//!     r#"{"timeout":{"hours":1,"minutes":30}}"#
+//! );
+//! ```
+
+use alloc::string::String;
+use core::fmt;
+use core::time::Duration;
+use serde::ser::SerializeMap;
+use serde::{de, Deserializer, Serializer};
+
+use crate::nanos_to_duration;
+
+/// The keys of a duration object, largest first.
+const FIELD_NAMES: [&str; 7] = [
+    "days", "hours", "minutes", "seconds", "millis", "micros", "nanos",
+];
+
+/// The length of one of each of [`FIELD_NAMES`] in nanoseconds.
+const FIELD_NANOS: [u128; FIELD_NAMES.len()] = [
+    86_400_000_000_000,
+    3_600_000_000_000,
+    60_000_000_000,
+    1_000_000_000,
+    1_000_000,
+    1_000,
+    1,
+];
+
+/// Serializes a duration as the minimal object of components, e.g.
+/// `{"hours": 1, "minutes": 30}`.
+///
+/// # Arguments
+///
+/// * `duration` - The duration to serialize
+/// * `serializer` - The serde serializer
+///
+/// # Returns
+///
+/// A result containing the serialized object if serialization was successful,
+/// or an error if serialization failed.
+pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut remaining = duration.as_nanos();
+    let mut components = [0u128; FIELD_NAMES.len()];
+    for (component, unit_nanos) in components.iter_mut().zip(FIELD_NANOS) {
+        *component = remaining / unit_nanos;
+        remaining %= unit_nanos;
+    }
+
+    let len = components.iter().filter(|value| **value > 0).count();
+    let mut map = serializer.serialize_map(Some(len))?;
+    for (value, name) in components.iter().zip(FIELD_NAMES) {
+        if *value > 0 {
+            // Every component fits in a u64, even the days of `Duration::MAX`
+            map.serialize_entry(name, &(*value as u64))?;
+        }
+    }
+    map.end()
+}
+
+/// Deserializes a duration from an object of components such as
+/// `{"hours": 1, "minutes": 30}`.
+///
+/// # Arguments
+///
+/// * `deserializer` - The serde deserializer
+///
+/// # Returns
+///
+/// A result containing the deserialized duration if deserialization was successful,
+/// or an error if deserialization failed.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_map(ObjectVisitor)
+}
+
+struct ObjectVisitor;
+
+impl<'de> de::Visitor<'de> for ObjectVisitor {
+    type Value = Duration;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an object such as {\"hours\": 1, \"minutes\": 30}")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Duration, A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let mut seen = [false; FIELD_NAMES.len()];
+        let mut total: u128 = 0;
+        while let Some(key) = map.next_key::<String>()? {
+            let index = match FIELD_NAMES.iter().position(|name| *name == key) {
+                Some(index) => index,
+                None => return Err(de::Error::unknown_field(&key, &FIELD_NAMES)),
+            };
+            if seen[index] {
+                return Err(de::Error::duplicate_field(FIELD_NAMES[index]));
+            }
+            seen[index] = true;
+
+            let value: u64 = map.next_value()?;
+            total = u128::from(value)
+                .checked_mul(FIELD_NANOS[index])
+                .and_then(|nanos| total.checked_add(nanos))
+                .ok_or_else(|| de::Error::custom("duration is too large"))?;
+        }
+        nanos_to_duration(total).ok_or_else(|| de::Error::custom("duration is too large"))
+    }
+}
@@ -0,0 +1,70 @@
+//! Serialization of `Option<Duration>` values.
+//!
+//! `Some` durations use the same string format as the crate root, while `None`
+//! is serialized as `null`. Add `#[serde(default)]` to the field as well if it
+//! may be missing from the input entirely.
+//!
+//! # Examples
+//!
+//! ```
+//! use core::time::Duration;
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct MyConfig {
+//!     #[serde(default, with = "serde_duration::option")]
+//!     timeout: Option<Duration>,
+//! }
+//!
+//! let config: MyConfig = serde_json::from_str(r#"{"timeout": "30s"}"#).unwrap();
+//! assert_eq!(config.timeout, Some(Duration::from_secs(30)));
+//!
+//! let config: MyConfig = serde_json::from_str("{}").unwrap();
+//! assert_eq!(config.timeout, None);
+//! ```
+
+use core::time::Duration;
+use serde::{Deserialize, Deserializer, Serializer};
+
+use crate::{DeserializeDuration, SerializeDuration};
+
+/// Serializes an optional duration as a duration string, or as `null` if it
+/// is `None`.
+///
+/// # Arguments
+///
+/// * `duration` - The optional duration to serialize
+/// * `serializer` - The serde serializer
+///
+/// # Returns
+///
+/// A result containing the serialized value if serialization was successful,
+/// or an error if serialization failed.
+pub fn serialize<S>(duration: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match duration {
+        Some(duration) => serializer.serialize_some(&SerializeDuration(duration)),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Deserializes an optional duration, mapping `null` to `None` and anything
+/// else through [`crate::deserialize`].
+///
+/// # Arguments
+///
+/// * `deserializer` - The serde deserializer
+///
+/// # Returns
+///
+/// A result containing the deserialized optional duration if deserialization
+/// was successful, or an error if deserialization failed.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let duration = Option::<DeserializeDuration>::deserialize(deserializer)?;
+    Ok(duration.map(|DeserializeDuration(duration)| duration))
+}
@@ -0,0 +1,178 @@
+use crate::{Unit, DEFAULT_MAX_LEN};
+
+/// How rigorously a duration string is checked, as chosen with
+/// [`ParseOptions::mode`] or [`parse_duration_with_mode`].
+///
+/// [`parse_duration_with_mode`]: crate::parse_duration_with_mode
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ParseMode {
+    /// Accepts only the canonical form that this crate writes: one or more
+    /// whole numbers, each directly followed by a lowercase short unit such
+    /// as "s" or "ms", with units from largest to smallest and none
+    /// repeated, e.g. "30s" or "1h30m". Whitespace, '+', '_' separators,
+    /// fractions, spelled-out units and uppercase units are all rejected.
+    Strict,
+    /// Accepts everything described in the crate documentation. This is the
+    /// default, and what the serde functions use.
+    #[default]
+    Lenient,
+}
+
+/// Options that change how [`parse_duration_with_options`] interprets a
+/// duration string.
+///
+/// [`parse_duration_with_options`]: crate::parse_duration_with_options
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use serde_duration::{parse_duration_with_options, ParseOptions};
+///
+/// // An average Gregorian month instead of the default 30 days
+/// let options = ParseOptions::new().secs_per_month(2_629_746);
+/// assert_eq!(
+///     parse_duration_with_options("1mo", &options).unwrap(),
+///     Duration::from_secs(2_629_746)
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParseOptions {
+    secs_per_month: u64,
+    secs_per_year: u64,
+    pub(crate) max_len: usize,
+    pub(crate) strict: bool,
+    pub(crate) saturating: bool,
+    pub(crate) decimal_comma: bool,
+    pub(crate) scientific: bool,
+    pub(crate) mode: ParseMode,
+}
+
+impl ParseOptions {
+    /// Creates the default options, which parse exactly like
+    /// [`parse_duration`](crate::parse_duration).
+    pub const fn new() -> Self {
+        ParseOptions {
+            secs_per_month: 30 * 86400,
+            secs_per_year: 365 * 86400,
+            max_len: DEFAULT_MAX_LEN,
+            strict: false,
+            saturating: false,
+            decimal_comma: false,
+            scientific: false,
+            mode: ParseMode::Lenient,
+        }
+    }
+
+    /// Sets the length of a month ("mo"), 30 days by default.
+    pub const fn secs_per_month(mut self, secs: u64) -> Self {
+        self.secs_per_month = secs;
+        self
+    }
+
+    /// Sets the length of a year ("y"), 365 days by default.
+    pub const fn secs_per_year(mut self, secs: u64) -> Self {
+        self.secs_per_year = secs;
+        self
+    }
+
+    /// Sets the length in bytes above which input is rejected without being
+    /// parsed, 256 by default.
+    pub const fn max_len(mut self, max_len: usize) -> Self {
+        self.max_len = max_len;
+        self
+    }
+
+    /// Sets whether compound durations must give each unit at most once and
+    /// from largest to smallest, so "1h30m" is accepted but "30m1h" and
+    /// "1h1h" are errors. Off by default, when every part is added up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use serde_duration::{parse_duration_with_options, DurationParseError, ParseOptions};
+    ///
+    /// let strict = ParseOptions::new().strict(true);
+    /// assert!(parse_duration_with_options("1h30m", &strict).is_ok());
+    /// let error = parse_duration_with_options("30m1h", &strict).unwrap_err();
+    /// assert_eq!(error.kind(), &DurationParseError::MisorderedUnit("h".to_string()));
+    /// ```
+    pub const fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Sets whether durations too large for a `Duration` become
+    /// `Duration::MAX` rather than an overflow error. Off by default; see
+    /// [`parse_duration_saturating`](crate::parse_duration_saturating).
+    pub const fn saturating(mut self, saturating: bool) -> Self {
+        self.saturating = saturating;
+        self
+    }
+
+    /// Sets whether a comma is accepted as the decimal separator, as in
+    /// "1,5h", as well as a dot. Off by default, when "1,5h" is an invalid
+    /// number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use serde_duration::{parse_duration_with_options, ParseOptions};
+    ///
+    /// let options = ParseOptions::new().decimal_comma(true);
+    /// assert_eq!(
+    ///     parse_duration_with_options("1,5h", &options).unwrap(),
+    ///     Duration::from_secs(5400)
+    /// );
+    /// ```
+    pub const fn decimal_comma(mut self, decimal_comma: bool) -> Self {
+        self.decimal_comma = decimal_comma;
+        self
+    }
+
+    /// Sets whether numbers may use scientific notation, as in "1e3s" or
+    /// "1.5e-3h". These are parsed exactly, like any other number. Off by
+    /// default, when "1e3s" is an invalid number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use serde_duration::{parse_duration_with_options, ParseOptions};
+    ///
+    /// let options = ParseOptions::new().scientific(true);
+    /// assert_eq!(
+    ///     parse_duration_with_options("1e3s", &options).unwrap(),
+    ///     Duration::from_secs(1000)
+    /// );
+    /// ```
+    pub const fn scientific(mut self, scientific: bool) -> Self {
+        self.scientific = scientific;
+        self
+    }
+
+    /// Sets the parsing mode, [`ParseMode::Lenient`] by default.
+    /// [`ParseMode::Strict`] implies [`strict`](ParseOptions::strict)
+    /// ordering and rejects everything but the canonical form, whatever the
+    /// other options allow.
+    pub const fn mode(mut self, mode: ParseMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// The length of one of `unit` in nanoseconds under these options.
+    pub(crate) fn unit_nanos(&self, unit: Unit) -> u128 {
+        match unit {
+            Unit::Months => u128::from(self.secs_per_month) * 1_000_000_000,
+            Unit::Years => u128::from(self.secs_per_year) * 1_000_000_000,
+            unit => unit.nanos(),
+        }
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions::new()
+    }
+}
@@ -0,0 +1,196 @@
+//! Serialization of a `Duration` as a `[value, "unit"]` pair.
+//!
+//! Some upstream formats split a duration into a number and a unit string,
+//! as in `[30, "s"]`. The unit may be any suffix or alias the crate root
+//! accepts, in any case, and the value may have a fraction, as in
+//! `[1.5, "h"]`. Durations are serialized as a whole number of the largest
+//! unit that represents them exactly, the same unit that [`crate::serialize`]
+//! would use.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::time::Duration;
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Upstream {
+//!     #[serde(with = "serde_duration::pair")]
+//!     timeout: Duration,
+//! }
+//!
+//! let upstream: Upstream = serde_json::from_str(r#"{"timeout": [30, "s"]}"#).unwrap();
+//! assert_eq!(upstream.timeout, Duration::from_secs(30));
+//!
+//! let upstream = Upstream { timeout: Duration::from_secs(5400) };
+//! assert_eq!(serde_json::to_string(&upstream).unwrap(), r#"{"timeout":[90,"m"]}"#);
+//! ```
+
+use alloc::string::{String, ToString};
+use core::fmt;
+use core::time::Duration;
+use serde::de::{self, SeqAccess, Visitor};
+use serde::ser::SerializeTuple;
+use serde::{Deserialize, Deserializer, Serializer};
+
+use crate::{arbitrary_precision_number, exact_unit, parse_number, ParseOptions, Unit};
+
+/// Serializes a duration as a two-element sequence of a whole number and a
+/// unit suffix, such as `[90, "m"]`.
+///
+/// # Arguments
+///
+/// * `duration` - The duration to serialize
+/// * `serializer` - The serde serializer
+///
+/// # Returns
+///
+/// A result containing the serialized sequence if serialization was
+/// successful, or an error if serialization failed.
+pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let nanos = duration.as_nanos();
+    let unit = if nanos == 0 {
+        Unit::Seconds
+    } else {
+        exact_unit(nanos)
+    };
+    let value = nanos / unit.nanos();
+
+    let mut tuple = serializer.serialize_tuple(2)?;
+    // Values that fit are written as a u64, which every format supports;
+    // only the longest durations in nanoseconds need a u128.
+    match u64::try_from(value) {
+        Ok(small) => tuple.serialize_element(&small)?,
+        Err(_) => tuple.serialize_element(&value)?,
+    }
+    tuple.serialize_element(unit.suffix())?;
+    tuple.end()
+}
+
+/// Deserializes a duration from a two-element sequence of a number and a unit
+/// string, such as `[30, "s"]` or `[1.5, "hours"]`.
+///
+/// # Arguments
+///
+/// * `deserializer` - The serde deserializer
+///
+/// # Returns
+///
+/// A result containing the deserialized duration if deserialization was
+/// successful, or an error if deserialization failed.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_tuple(2, PairVisitor)
+}
+
+/// Visitor combining a `[value, "unit"]` sequence into a duration.
+struct PairVisitor;
+
+impl<'de> Visitor<'de> for PairVisitor {
+    type Value = Duration;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a sequence of a number and a unit, such as [30, \"s\"]")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Duration, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let Value(value) = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let unit: String = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let unit: Unit = unit.parse().map_err(de::Error::custom)?;
+        // Exponents can only come from serde_json's arbitrary_precision.
+        let options = ParseOptions::new().scientific(true);
+        parse_number(&value, unit.nanos(), &options).map_err(de::Error::custom)
+    }
+}
+
+/// The value of a pair, kept in decimal so that fractions such as 0.1 are
+/// scaled by the unit exactly.
+struct Value(String);
+
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+struct ValueVisitor;
+
+impl<'de> Visitor<'de> for ValueVisitor {
+    type Value = Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a non-negative number")
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Value(value.to_string()))
+    }
+
+    fn visit_u128<E>(self, value: u128) -> Result<Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(Value(value.to_string()))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Value, E>
+    where
+        E: de::Error,
+    {
+        match u64::try_from(value) {
+            Ok(value) => self.visit_u64(value),
+            Err(_) => Err(E::invalid_value(de::Unexpected::Signed(value), &self)),
+        }
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Value, E>
+    where
+        E: de::Error,
+    {
+        // `f64`'s Display never uses an exponent, so it parses like "1.5s".
+        if value.is_finite() && value >= 0.0 {
+            Ok(Value(value.to_string()))
+        } else {
+            Err(E::invalid_value(de::Unexpected::Float(value), &self))
+        }
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<Value, A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        let Some(number) = arbitrary_precision_number(map)? else {
+            return Err(de::Error::invalid_type(de::Unexpected::Map, &self));
+        };
+        if !number.starts_with('-') {
+            return Ok(Value(number));
+        }
+        // Negative numbers are rejected as they would be without the feature.
+        match (number.parse::<i64>(), number.parse::<f64>()) {
+            (Ok(value), _) => self.visit_i64(value),
+            (_, Ok(value)) => self.visit_f64(value),
+            _ => Err(de::Error::invalid_value(
+                de::Unexpected::Str(&number),
+                &self,
+            )),
+        }
+    }
+}
@@ -0,0 +1,22 @@
+/// How to round a duration that doesn't fit a whole number of units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Rounds toward zero, e.g. 90 seconds become "1m".
+    Truncate,
+    /// Rounds to the nearest unit with halves rounded up, e.g. 89 seconds
+    /// become "1m" and 90 seconds become "2m".
+    Round,
+    /// Rounds up, e.g. 61 seconds become "2m".
+    Ceil,
+}
+
+impl RoundingMode {
+    /// Divides `nanos` by `unit_nanos`, rounding the quotient in this mode.
+    pub(crate) fn divide(self, nanos: u128, unit_nanos: u128) -> u128 {
+        match self {
+            RoundingMode::Truncate => nanos / unit_nanos,
+            RoundingMode::Round => (nanos + unit_nanos / 2) / unit_nanos,
+            RoundingMode::Ceil => nanos.div_ceil(unit_nanos),
+        }
+    }
+}
@@ -0,0 +1,68 @@
+//! Serialization of `Duration` values as a number of seconds.
+//!
+//! Durations are always written as a number of seconds, e.g. "90s" rather
+//! than "1m30s", which keeps serialized configs stable and easy to diff.
+//! Sub-second precision is kept as a decimal fraction, e.g. "1.5s".
+//! Deserialization accepts every unit the crate root does, so existing values
+//! such as "1m" still load.
+//!
+//! # Examples
+//!
+//! ```
+//! use core::time::Duration;
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct MyConfig {
+//!     #[serde(with = "serde_duration::seconds")]
+//!     timeout: Duration,
+//! }
+//!
+//! let config = MyConfig { timeout: Duration::from_secs(90) };
+//! assert_eq!(serde_json::to_string(&config).unwrap(), r#"{"timeout":"90s"}"#);
+//! ```
+
+use alloc::string::String;
+use core::time::Duration;
+use serde::{Deserializer, Serializer};
+
+use crate::write_fraction;
+
+/// Serializes a duration as a number of seconds such as "90s".
+///
+/// # Arguments
+///
+/// * `duration` - The duration to serialize
+/// * `serializer` - The serde serializer
+///
+/// # Returns
+///
+/// A result containing the serialized string if serialization was successful,
+/// or an error if serialization failed.
+pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut s = String::new();
+    write_fraction(&mut s, duration.as_nanos(), 9);
+    s.push('s');
+    serializer.serialize_str(&s)
+}
+
+/// Deserializes a duration from a string, accepting everything that
+/// [`crate::deserialize`] accepts.
+///
+/// # Arguments
+///
+/// * `deserializer` - The serde deserializer
+///
+/// # Returns
+///
+/// A result containing the deserialized duration if deserialization was successful,
+/// or an error if deserialization failed.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    crate::deserialize(deserializer)
+}
@@ -0,0 +1,74 @@
+//! Serialization of `Duration` values as a plain number of seconds.
+//!
+//! Durations are written as an unsigned integer, e.g. `90`, rather than a
+//! string. This keeps binary formats such as bincode or MessagePack compact
+//! and suits schemas that expect a number. Only whole seconds can be
+//! represented, so serializing a duration with a sub-second component fails
+//! instead of silently losing precision.
+//!
+//! Deserialization reads a number of seconds, which may also be a non-negative
+//! float in self-describing formats such as JSON.
+//!
+//! # Examples
+//!
+//! ```
+//! use core::time::Duration;
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct MyConfig {
+//!     #[serde(with = "serde_duration::secs_number")]
+//!     timeout: Duration,
+//! }
+//!
+//! let config: MyConfig = serde_json::from_str(r#"{"timeout": 90}"#).unwrap();
+//! assert_eq!(config.timeout, Duration::from_secs(90));
+//! assert_eq!(serde_json::to_string(&config).unwrap(), r#"{"timeout":90}"#);
+//! ```
+
+use core::time::Duration;
+use serde::{ser, Deserializer, Serializer};
+
+use crate::DurationVisitor;
+
+/// Serializes a duration as an unsigned integer number of seconds.
+///
+/// # Arguments
+///
+/// * `duration` - The duration to serialize
+/// * `serializer` - The serde serializer
+///
+/// # Returns
+///
+/// A result containing the serialized number if serialization was successful,
+/// or an error if serialization failed or the duration isn't a whole number of
+/// seconds.
+pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if duration.subsec_nanos() != 0 {
+        return Err(ser::Error::custom(format_args!(
+            "{:?} is not a whole number of seconds",
+            duration
+        )));
+    }
+    serializer.serialize_u64(duration.as_secs())
+}
+
+/// Deserializes a duration from a number of seconds.
+///
+/// # Arguments
+///
+/// * `deserializer` - The serde deserializer
+///
+/// # Returns
+///
+/// A result containing the deserialized duration if deserialization was successful,
+/// or an error if deserialization failed.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_u64(DurationVisitor::default())
+}
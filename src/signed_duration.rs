@@ -0,0 +1,129 @@
+use core::fmt;
+use core::str::FromStr;
+use core::time::Duration;
+
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{format_signed, parse_signed, visit_whole_number, InvalidDurationError};
+
+/// A duration that may be negative, such as a clock skew or an offset,
+/// without depending on chrono or time.
+///
+/// It is parsed from and serialized to the same strings as a `Duration`, with
+/// an optional leading '-', e.g. "-30s" or "1h30m". A leading '+' is accepted
+/// as well. Negative zero is normalized to zero, so it is never written as
+/// "-0s".
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use serde_duration::SignedDuration;
+///
+/// let skew: SignedDuration = "-30s".parse().unwrap();
+/// assert!(skew.negative);
+/// assert_eq!(skew.magnitude, Duration::from_secs(30));
+/// assert_eq!(skew.to_string(), "-30s");
+/// assert_eq!(serde_json::to_string(&skew).unwrap(), r#""-30s""#);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct SignedDuration {
+    /// Whether the duration is negative.
+    pub negative: bool,
+    /// The absolute value of the duration.
+    pub magnitude: Duration,
+}
+
+impl SignedDuration {
+    /// Creates a signed duration, treating a negative zero as zero.
+    pub const fn new(negative: bool, magnitude: Duration) -> Self {
+        SignedDuration {
+            negative: negative && !magnitude.is_zero(),
+            magnitude,
+        }
+    }
+}
+
+impl From<Duration> for SignedDuration {
+    fn from(magnitude: Duration) -> Self {
+        SignedDuration::new(false, magnitude)
+    }
+}
+
+impl FromStr for SignedDuration {
+    type Err = InvalidDurationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, magnitude) = parse_signed(s)?;
+        Ok(SignedDuration::new(negative, magnitude))
+    }
+}
+
+impl fmt::Display for SignedDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&format_signed(self.negative, &self.magnitude))
+    }
+}
+
+impl Serialize for SignedDuration {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&format_signed(self.negative, &self.magnitude))
+    }
+}
+
+impl<'de> Deserialize<'de> for SignedDuration {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(SignedDurationVisitor)
+        } else {
+            deserializer.deserialize_str(SignedDurationVisitor)
+        }
+    }
+}
+
+/// Visitor accepting a signed duration string or a signed number of seconds.
+struct SignedDurationVisitor;
+
+impl<'de> Visitor<'de> for SignedDurationVisitor {
+    type Value = SignedDuration;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a duration string such as \"-30s\" or a number of seconds")
+    }
+
+    fn visit_str<E>(self, s: &str) -> Result<SignedDuration, E>
+    where
+        E: de::Error,
+    {
+        s.parse().map_err(E::custom)
+    }
+
+    fn visit_u64<E>(self, secs: u64) -> Result<SignedDuration, E>
+    where
+        E: de::Error,
+    {
+        Ok(Duration::from_secs(secs).into())
+    }
+
+    fn visit_i64<E>(self, secs: i64) -> Result<SignedDuration, E>
+    where
+        E: de::Error,
+    {
+        let magnitude = Duration::from_secs(secs.unsigned_abs());
+        Ok(SignedDuration::new(secs < 0, magnitude))
+    }
+
+    fn visit_map<A>(self, map: A) -> Result<SignedDuration, A::Error>
+    where
+        A: de::MapAccess<'de>,
+    {
+        visit_whole_number(self, map)
+    }
+}
@@ -0,0 +1,102 @@
+use alloc::string::ToString;
+use core::iter::FusedIterator;
+
+use crate::{
+    check_len, next_token, offset, parse_separated, strip_plus, trim, DurationParseError,
+    InvalidDurationError, Unit, DEFAULT_MAX_LEN,
+};
+
+/// An iterator over the `(value, unit)` components of a compound duration
+/// such as "1h30m15s", for callers that want to inspect the components rather
+/// than their sum.
+///
+/// Components are lexed as [`parse_duration`](crate::parse_duration) lexes
+/// them, with the same whitespace rules and unit aliases, and repeated or
+/// misordered units are yielded as they appear. Each value is a whole number
+/// of its own unit, as a `u128` since a count of nanoseconds may not fit in a
+/// `u64`. Values must be written without a fraction, so both "1.5h" and "1.0h"
+/// yield an error rather than a component. A bare "0", which `parse_duration`
+/// accepts as zero, has no components at all.
+///
+/// A malformed component yields an error, after which iteration ends.
+///
+/// # Examples
+///
+/// ```
+/// use serde_duration::{DurationTokens, Unit};
+///
+/// let tokens: Vec<_> = DurationTokens::new("1h 30 minutes")
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+/// assert_eq!(tokens, [(1, Unit::Hours), (30, Unit::Minutes)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DurationTokens<'a> {
+    input: &'a str,
+    /// What's left to lex, or `None` before the first component.
+    rest: Option<&'a str>,
+    done: bool,
+}
+
+impl<'a> DurationTokens<'a> {
+    /// Creates an iterator over the components of `s`.
+    pub fn new(s: &'a str) -> Self {
+        DurationTokens {
+            input: s,
+            rest: None,
+            done: false,
+        }
+    }
+
+    fn next_component(&mut self) -> Result<Option<(u128, Unit)>, InvalidDurationError> {
+        let s = self.input;
+        let error = |reason, at| InvalidDurationError::new(s, reason).at(at);
+        let (rest, is_first) = match self.rest {
+            Some("") => return Ok(None),
+            Some(rest) => (rest, false),
+            None => {
+                check_len(s, DEFAULT_MAX_LEN)?;
+                let trimmed = trim(s);
+                let unsigned = strip_plus(trimmed)
+                    .map_err(|(reason, at)| error(reason, offset(s, trimmed) + at))?;
+                if unsigned == "0" {
+                    return Ok(None);
+                }
+                (unsigned, true)
+            }
+        };
+
+        let (token, tail) = match next_token(s, rest, is_first) {
+            Ok(Some(next)) => next,
+            Ok(None) => return Err(InvalidDurationError::unrecognized(s)),
+            Err((reason, at)) => return Err(error(reason, at)),
+        };
+        self.rest = Some(tail);
+        let number = token.number;
+        if number.contains(['.', ',']) {
+            let reason = DurationParseError::InvalidNumber(number.to_string());
+            return Err(error(reason, offset(s, number)));
+        }
+        // Parsing the value in its unit checks its separators and range, and
+        // a whole number of the unit divides back exactly.
+        let unit_nanos = token.unit.nanos();
+        let value = parse_separated(number, unit_nanos)
+            .map_err(|reason| error(reason, offset(s, number)))?;
+        Ok(Some((value.as_nanos() / unit_nanos, token.unit)))
+    }
+}
+
+impl Iterator for DurationTokens<'_> {
+    type Item = Result<(u128, Unit), InvalidDurationError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let next = self.next_component().transpose();
+        self.done = !matches!(next, Some(Ok(_)));
+        next
+    }
+}
+
+impl FusedIterator for DurationTokens<'_> {}
@@ -0,0 +1,69 @@
+//! Serialization of `(Duration, Duration)` pairs.
+//!
+//! Both elements use the same string format as the crate root, so a pair is
+//! serialized as a two-element array such as `["1s","30s"]`, e.g. for a
+//! minimum and maximum backoff.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::time::Duration;
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct RetryConfig {
+//!     #[serde(with = "serde_duration::tuple2")]
+//!     backoff: (Duration, Duration),
+//! }
+//!
+//! let config: RetryConfig = serde_json::from_str(r#"{"backoff": ["1s", "30s"]}"#).unwrap();
+//! assert_eq!(config.backoff, (Duration::from_secs(1), Duration::from_secs(30)));
+//! ```
+
+use core::time::Duration;
+use serde::ser::SerializeTuple;
+use serde::{Deserialize, Deserializer, Serializer};
+
+use crate::{DeserializeDuration, SerializeDuration};
+
+/// Serializes a pair of durations as a two-element sequence of duration
+/// strings.
+///
+/// # Arguments
+///
+/// * `durations` - The pair to serialize
+/// * `serializer` - The serde serializer
+///
+/// # Returns
+///
+/// A result containing the serialized sequence if serialization was
+/// successful, or an error if serialization failed.
+pub fn serialize<S>(durations: &(Duration, Duration), serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut tuple = serializer.serialize_tuple(2)?;
+    tuple.serialize_element(&SerializeDuration(&durations.0))?;
+    tuple.serialize_element(&SerializeDuration(&durations.1))?;
+    tuple.end()
+}
+
+/// Deserializes a pair of durations from a two-element sequence of duration
+/// strings.
+///
+/// # Arguments
+///
+/// * `deserializer` - The serde deserializer
+///
+/// # Returns
+///
+/// A result containing the deserialized pair if deserialization was
+/// successful, or an error if deserialization failed.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<(Duration, Duration), D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let (DeserializeDuration(first), DeserializeDuration(second)) =
+        Deserialize::deserialize(deserializer)?;
+    Ok((first, second))
+}
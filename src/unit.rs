@@ -0,0 +1,280 @@
+use alloc::string::{String, ToString};
+use core::fmt;
+use core::str::FromStr;
+use core::time::Duration;
+
+/// A unit of time understood by the parser and formatter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Unit {
+    /// Nanoseconds, "ns"
+    Nanos,
+    /// Microseconds, "us" or "µs"
+    Micros,
+    /// Milliseconds, "ms"
+    Millis,
+    /// Seconds, "s"
+    Seconds,
+    /// Minutes, "m"
+    Minutes,
+    /// Hours, "h"
+    Hours,
+    /// Days of 24 hours, "d"
+    Days,
+    /// Weeks of 7 days, "w"
+    Weeks,
+    /// Months of 30 days, "mo". Calendar months vary in length, so this is
+    /// only ever used when formatting if asked for explicitly.
+    Months,
+    /// Years of 365 days, "y". Like months this is an approximation that the
+    /// formatter only uses when asked for explicitly.
+    Years,
+}
+
+/// The suffix and length in nanoseconds of each unit, in the order of the
+/// `Unit` variants, which is from smallest to largest. The parser reads
+/// suffixes and the formatter writes them from this one table, so a unit
+/// added here is understood in both directions. The approximate months and
+/// years come last.
+pub(crate) const UNITS: [(Unit, &str, u128); 10] = [
+    (Unit::Nanos, "ns", 1),
+    (Unit::Micros, "us", 1_000),
+    (Unit::Millis, "ms", 1_000_000),
+    (Unit::Seconds, "s", 1_000_000_000),
+    (Unit::Minutes, "m", 60_000_000_000),
+    (Unit::Hours, "h", 3_600_000_000_000),
+    (Unit::Days, "d", 86_400_000_000_000),
+    (Unit::Weeks, "w", 604_800_000_000_000),
+    (Unit::Months, "mo", 2_592_000_000_000_000),
+    (Unit::Years, "y", 31_536_000_000_000_000),
+];
+
+/// How many of the leading entries of [`UNITS`] are exact.
+const EXACT_UNITS: usize = 8;
+
+// Each unit's row must be at its variant's index, since `suffix` and `nanos`
+// index the table with it.
+const _: () = {
+    let mut i = 0;
+    while i < UNITS.len() {
+        assert!(UNITS[i].0 as usize == i);
+        i += 1;
+    }
+};
+
+/// The first `N` units of [`UNITS`], largest first.
+const fn descending<const N: usize>() -> [Unit; N] {
+    let mut units = [Unit::Nanos; N];
+    let mut i = 0;
+    while i < N {
+        units[i] = UNITS[N - 1 - i].0;
+        i += 1;
+    }
+    units
+}
+
+/// Every other spelling of a unit that the parser accepts besides its suffix,
+/// matched case-insensitively. The parser and the serde entry points all look
+/// units up here and in [`UNITS`].
+const ALIASES: &[(&str, Unit)] = &[
+    ("nsec", Unit::Nanos),
+    ("nsecs", Unit::Nanos),
+    ("nanos", Unit::Nanos),
+    ("nanosecond", Unit::Nanos),
+    ("nanoseconds", Unit::Nanos),
+    ("µs", Unit::Micros),
+    ("usec", Unit::Micros),
+    ("usecs", Unit::Micros),
+    ("micros", Unit::Micros),
+    ("microsecond", Unit::Micros),
+    ("microseconds", Unit::Micros),
+    ("msec", Unit::Millis),
+    ("msecs", Unit::Millis),
+    ("millis", Unit::Millis),
+    ("millisecond", Unit::Millis),
+    ("milliseconds", Unit::Millis),
+    ("sec", Unit::Seconds),
+    ("secs", Unit::Seconds),
+    ("second", Unit::Seconds),
+    ("seconds", Unit::Seconds),
+    ("min", Unit::Minutes),
+    ("mins", Unit::Minutes),
+    ("minute", Unit::Minutes),
+    ("minutes", Unit::Minutes),
+    ("hr", Unit::Hours),
+    ("hrs", Unit::Hours),
+    ("hour", Unit::Hours),
+    ("hours", Unit::Hours),
+    ("day", Unit::Days),
+    ("days", Unit::Days),
+    ("wk", Unit::Weeks),
+    ("wks", Unit::Weeks),
+    ("week", Unit::Weeks),
+    ("weeks", Unit::Weeks),
+    ("mos", Unit::Months),
+    ("month", Unit::Months),
+    ("months", Unit::Months),
+    ("yr", Unit::Years),
+    ("yrs", Unit::Years),
+    ("year", Unit::Years),
+    ("years", Unit::Years),
+];
+
+// Units are matched case-insensitively, so no two spellings may differ only
+// in case, or "M" could mean both minutes and months.
+const _: () = {
+    let mut i = 0;
+    while i < UNITS.len() + ALIASES.len() {
+        let mut j = i + 1;
+        while j < UNITS.len() + ALIASES.len() {
+            assert!(!eq_ignore_case(spelling(i), spelling(j)));
+            j += 1;
+        }
+        i += 1;
+    }
+};
+
+/// The `i`th spelling of a unit, counting the suffixes in [`UNITS`] and then
+/// [`ALIASES`].
+const fn spelling(i: usize) -> &'static [u8] {
+    if i < UNITS.len() {
+        UNITS[i].1.as_bytes()
+    } else {
+        ALIASES[i - UNITS.len()].0.as_bytes()
+    }
+}
+
+/// Whether `a` and `b` are equal, ignoring ASCII case.
+pub(crate) const fn eq_ignore_case(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if !a[i].eq_ignore_ascii_case(&b[i]) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+impl Unit {
+    /// Looks up the unit with the given suffix or alias, ignoring case.
+    pub(crate) fn from_alias(alias: &str) -> Option<Unit> {
+        let suffixes = UNITS.iter().map(|(unit, suffix, _)| (*suffix, *unit));
+        suffixes
+            .chain(ALIASES.iter().copied())
+            .find(|(known, _)| known.eq_ignore_ascii_case(alias))
+            .map(|(_, unit)| unit)
+    }
+
+    /// All exact units, largest first. Months and years are left out since
+    /// they are only approximations.
+    pub(crate) const DESCENDING: [Unit; EXACT_UNITS] = descending();
+
+    /// Every unit, largest first.
+    pub(crate) const ALL: [Unit; UNITS.len()] = descending();
+
+    /// The suffix this unit is written with.
+    pub(crate) const fn suffix(self) -> &'static str {
+        UNITS[self as usize].1
+    }
+
+    /// The unit's name in English, in the singular.
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Unit::Nanos => "nanosecond",
+            Unit::Micros => "microsecond",
+            Unit::Millis => "millisecond",
+            Unit::Seconds => "second",
+            Unit::Minutes => "minute",
+            Unit::Hours => "hour",
+            Unit::Days => "day",
+            Unit::Weeks => "week",
+            Unit::Months => "month",
+            Unit::Years => "year",
+        }
+    }
+
+    /// Returns the length of one of this unit as a `Duration`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use serde_duration::Unit;
+    ///
+    /// assert_eq!(Unit::Hours.as_duration(), Duration::from_secs(3600));
+    /// assert_eq!(Unit::Micros.as_duration(), Duration::from_micros(1));
+    /// ```
+    pub fn as_duration(self) -> Duration {
+        let nanos = self.nanos();
+        Duration::new(
+            (nanos / 1_000_000_000) as u64,
+            (nanos % 1_000_000_000) as u32,
+        )
+    }
+
+    /// Returns how many seconds one of this unit is, or `None` for the units
+    /// shorter than a second, which are not a whole number of seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use serde_duration::Unit;
+    ///
+    /// assert_eq!(Unit::Minutes.as_secs_multiplier(), Some(60));
+    /// assert_eq!(Unit::Millis.as_secs_multiplier(), None);
+    /// ```
+    pub fn as_secs_multiplier(self) -> Option<u64> {
+        let nanos = self.nanos();
+        if nanos < 1_000_000_000 {
+            return None;
+        }
+        Some((nanos / 1_000_000_000) as u64)
+    }
+
+    /// The length of one of this unit in nanoseconds.
+    pub(crate) const fn nanos(self) -> u128 {
+        UNITS[self as usize].2
+    }
+}
+
+/// Parses a unit from its suffix or any of its aliases, ignoring case, e.g.
+/// "ms", "hours" or "MIN".
+///
+/// The lowercase suffix is the canonical spelling, and no two units have
+/// spellings that differ only in case, so matching case-insensitively is never
+/// ambiguous. In particular "M" is minutes, and months are "mo".
+impl FromStr for Unit {
+    type Err = ParseUnitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Unit::from_alias(s).ok_or_else(|| ParseUnitError {
+            unit: s.to_string(),
+        })
+    }
+}
+
+/// Writes the unit's short suffix, e.g. "ms", which parses back to the same
+/// unit.
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.suffix())
+    }
+}
+
+/// The error returned when a string is not a known unit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseUnitError {
+    unit: String,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseUnitError {}
+
+impl fmt::Display for ParseUnitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown unit '{}'", self.unit)
+    }
+}
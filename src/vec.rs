@@ -0,0 +1,97 @@
+//! Serialization of `Vec<Duration>` values.
+//!
+//! Each element uses the same string format as the crate root, so a vector
+//! is serialized as e.g. `["1s","5s","30s"]`.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::time::Duration;
+//! use serde::{Serialize, Deserialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct RetryConfig {
+//!     #[serde(with = "serde_duration::vec")]
+//!     backoff: Vec<Duration>,
+//! }
+//!
+//! let config: RetryConfig = serde_json::from_str(r#"{"backoff": ["1s", "5s"]}"#).unwrap();
+//! assert_eq!(config.backoff, vec![Duration::from_secs(1), Duration::from_secs(5)]);
+//! ```
+
+use alloc::format;
+use alloc::vec::Vec;
+use core::fmt;
+use core::time::Duration;
+use serde::{de, Deserializer, Serializer};
+
+use crate::{DeserializeDuration, SerializeDuration};
+
+/// Serializes a vector of durations as a sequence of duration strings.
+///
+/// # Arguments
+///
+/// * `durations` - The durations to serialize
+/// * `serializer` - The serde serializer
+///
+/// # Returns
+///
+/// A result containing the serialized sequence if serialization was
+/// successful, or an error if serialization failed.
+pub fn serialize<S>(durations: &[Duration], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_seq(durations.iter().map(SerializeDuration))
+}
+
+/// Deserializes a vector of durations from a sequence of duration strings.
+///
+/// Fails on the first element that is not a valid duration, naming its index
+/// in the error.
+///
+/// # Arguments
+///
+/// * `deserializer` - The serde deserializer
+///
+/// # Returns
+///
+/// A result containing the deserialized durations if deserialization was
+/// successful, or an error if deserialization failed.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<Duration>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_seq(VecVisitor)
+}
+
+/// The most durations preallocated from a sequence's size hint.
+const MAX_PREALLOCATED: usize = 4096;
+
+struct VecVisitor;
+
+impl<'de> de::Visitor<'de> for VecVisitor {
+    type Value = Vec<Duration>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a sequence of durations")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Vec<Duration>, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        // The hint comes from the input, so it is capped before allocating.
+        let capacity = seq.size_hint().unwrap_or(0).min(MAX_PREALLOCATED);
+        let mut durations = Vec::with_capacity(capacity);
+        loop {
+            let element = seq
+                .next_element::<DeserializeDuration>()
+                .map_err(|e| de::Error::custom(format!("at index {}: {}", durations.len(), e)))?;
+            match element {
+                Some(DeserializeDuration(duration)) => durations.push(duration),
+                None => return Ok(durations),
+            }
+        }
+    }
+}
@@ -0,0 +1,223 @@
+//! Support code for [`serde_duration_with!`](crate::serde_duration_with). The
+//! items in here are only public so that the macro can reach them, and are
+//! not part of the crate's API.
+
+use core::time::Duration;
+use serde::{de, ser, Deserializer, Serializer};
+
+pub use serde;
+
+use crate::{format_duration, format_duration_as, format_duration_compound, DurationVisitor, Unit};
+
+/// Generates a module with `serialize` and `deserialize` functions for use in
+/// `#[serde(with = "...")]`, configured by the given options.
+///
+/// The options are a comma separated list of `key = value` pairs:
+///
+/// * `unit = <Unit>` - always serialize as a whole number of this unit, e.g.
+///   `unit = Millis`. Serialization fails for durations that aren't a whole
+///   number of the unit.
+/// * `default_unit = <Unit>` - interpret strings that are a bare number, such
+///   as "30", in this unit instead of in seconds.
+/// * `compound = <bool>` - serialize losslessly with every component, like
+///   [`compound`](crate::compound), e.g. "1m30s".
+/// * `infinite = <bool>` - map `Duration::MAX` to and from "inf", like
+///   [`infinite`](crate::infinite).
+/// * `min = <Duration>` and `max = <Duration>` - reject deserialized
+///   durations outside of these inclusive bounds, which can be any constant
+///   expression, e.g. `max = Duration::from_secs(3600)`. The error names the
+///   bound that was exceeded.
+///
+/// Without options the generated module behaves exactly like the crate root.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use serde::{Serialize, Deserialize};
+/// use serde_duration::serde_duration_with;
+///
+/// serde_duration_with!(mod as_millis, unit = Millis);
+/// serde_duration_with!(mod lenient, default_unit = Seconds, compound = true);
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct MyConfig {
+///     #[serde(with = "as_millis")]
+///     latency: Duration,
+///     #[serde(with = "lenient")]
+///     timeout: Duration,
+/// }
+///
+/// let config: MyConfig = serde_json::from_str(r#"{"latency": "1s", "timeout": "90"}"#).unwrap();
+/// assert_eq!(config.timeout, Duration::from_secs(90));
+/// assert_eq!(
+///     serde_json::to_string(&config).unwrap(),
+///     r#"{"latency":"1000ms","timeout":"1m30s"}"#
+/// );
+/// ```
+#[macro_export]
+macro_rules! serde_duration_with {
+    ($vis:vis mod $name:ident $(, $($options:tt)*)?) => {
+        $vis mod $name {
+            // Bounds are expressions written at the call site, so they get to
+            // see the names imported there, such as `Duration`.
+            const CONFIG: $crate::with::Config = {
+                #[allow(unused_imports)]
+                use super::*;
+                $crate::serde_duration_with!(@config $crate::with::Config::new(), $($($options)*)?)
+            };
+
+            pub fn serialize<S>(
+                duration: &::core::time::Duration,
+                serializer: S,
+            ) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::with::serde::Serializer,
+            {
+                $crate::with::serialize(&CONFIG, duration, serializer)
+            }
+
+            pub fn deserialize<'de, D>(
+                deserializer: D,
+            ) -> ::core::result::Result<::core::time::Duration, D::Error>
+            where
+                D: $crate::with::serde::Deserializer<'de>,
+            {
+                $crate::with::deserialize(&CONFIG, deserializer)
+            }
+        }
+    };
+
+    (@config $config:expr, unit = $unit:ident $(, $($rest:tt)*)?) => {
+        $crate::serde_duration_with!(@config $config.unit($crate::Unit::$unit), $($($rest)*)?)
+    };
+    (@config $config:expr, default_unit = $unit:ident $(, $($rest:tt)*)?) => {
+        $crate::serde_duration_with!(
+            @config $config.default_unit($crate::Unit::$unit), $($($rest)*)?
+        )
+    };
+    (@config $config:expr, compound = $compound:literal $(, $($rest:tt)*)?) => {
+        $crate::serde_duration_with!(@config $config.compound($compound), $($($rest)*)?)
+    };
+    (@config $config:expr, infinite = $infinite:literal $(, $($rest:tt)*)?) => {
+        $crate::serde_duration_with!(@config $config.infinite($infinite), $($($rest)*)?)
+    };
+    (@config $config:expr, min = $min:expr $(, $($rest:tt)*)?) => {
+        $crate::serde_duration_with!(@config $config.min($min), $($($rest)*)?)
+    };
+    (@config $config:expr, max = $max:expr $(, $($rest:tt)*)?) => {
+        $crate::serde_duration_with!(@config $config.max($max), $($($rest)*)?)
+    };
+    (@config $config:expr, ) => {
+        $config
+    };
+}
+
+/// The options of a module generated by `serde_duration_with!`.
+#[derive(Debug, Clone, Copy)]
+pub struct Config {
+    unit: Option<Unit>,
+    default_unit: Unit,
+    compound: bool,
+    infinite: bool,
+    min: Option<Duration>,
+    max: Option<Duration>,
+}
+
+impl Config {
+    pub const fn new() -> Self {
+        Config {
+            unit: None,
+            default_unit: Unit::Seconds,
+            compound: false,
+            infinite: false,
+            min: None,
+            max: None,
+        }
+    }
+
+    pub const fn unit(mut self, unit: Unit) -> Self {
+        self.unit = Some(unit);
+        self
+    }
+
+    pub const fn default_unit(mut self, unit: Unit) -> Self {
+        self.default_unit = unit;
+        self
+    }
+
+    pub const fn compound(mut self, compound: bool) -> Self {
+        self.compound = compound;
+        self
+    }
+
+    pub const fn infinite(mut self, infinite: bool) -> Self {
+        self.infinite = infinite;
+        self
+    }
+
+    pub const fn min(mut self, min: Duration) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    pub const fn max(mut self, max: Duration) -> Self {
+        self.max = Some(max);
+        self
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config::new()
+    }
+}
+
+pub fn serialize<S>(config: &Config, duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if config.infinite && *duration == Duration::MAX {
+        serializer.serialize_str("inf")
+    } else if let Some(unit) = config.unit {
+        let s = format_duration_as(duration, unit, false).ok_or_else(|| {
+            ser::Error::custom(format_args!(
+                "{:?} is not a whole number of {}",
+                duration,
+                unit.suffix()
+            ))
+        })?;
+        serializer.serialize_str(&s)
+    } else if config.compound {
+        serializer.serialize_str(&format_duration_compound(duration))
+    } else {
+        crate::serialize(duration, serializer)
+    }
+}
+
+pub fn deserialize<'de, D>(config: &Config, deserializer: D) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let duration = DurationVisitor {
+        default_unit: config.default_unit,
+        infinite: config.infinite,
+    }
+    .deserialize(deserializer)?;
+
+    if let Some(min) = config.min.filter(|min| duration < *min) {
+        return Err(de::Error::custom(format_args!(
+            "duration {} is below the minimum of {}",
+            format_duration(&duration),
+            format_duration(&min)
+        )));
+    }
+    if let Some(max) = config.max.filter(|max| duration > *max) {
+        return Err(de::Error::custom(format_args!(
+            "duration {} is above the maximum of {}",
+            format_duration(&duration),
+            format_duration(&max)
+        )));
+    }
+    Ok(duration)
+}
@@ -0,0 +1,113 @@
+#![cfg(feature = "test_arbitrary_precision")]
+
+use serde::{Deserialize, Serialize};
+use serde_duration::{DurationStr, SignedDuration};
+use std::time::Duration;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TestStruct {
+    #[serde(with = "serde_duration")]
+    duration: Duration,
+}
+
+#[test]
+fn test_deserialize_arbitrary_precision_numbers() {
+    let tests = vec![
+        ("30", Duration::from_secs(30)),
+        ("1.5", Duration::from_millis(1500)),
+        ("0.1", Duration::from_millis(100)),
+        ("1.000000001", Duration::new(1, 1)),
+        ("1e3", Duration::from_secs(1000)),
+        ("2.5E-1", Duration::from_millis(250)),
+        ("18446744073709551615.999999999", Duration::MAX),
+    ];
+
+    for (input, expected) in tests {
+        let json = format!("{{\"duration\":{}}}", input);
+        let parsed: TestStruct = serde_json::from_str(&json)
+            .unwrap_or_else(|e| panic!("Failed to parse {}: {}", json, e));
+        assert_eq!(parsed.duration, expected, "{}", input);
+    }
+}
+
+#[test]
+fn test_deserialize_arbitrary_precision_invalid() {
+    let tests = vec![
+        ("-1", "invalid value: integer `-1`"),
+        ("-1.5", "invalid value: floating point `-1.5`"),
+        ("1e40", "invalid value: floating point"),
+        ("{\"seconds\":1}", "invalid type: map"),
+    ];
+
+    for (input, expected) in tests {
+        let json = format!("{{\"duration\":{}}}", input);
+        let error = serde_json::from_str::<TestStruct>(&json).unwrap_err();
+        assert!(
+            error.to_string().contains(expected),
+            "{:?} does not contain {:?}",
+            error.to_string(),
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_deserialize_arbitrary_precision_wrappers() {
+    let parsed: DurationStr = serde_json::from_str("1.5").unwrap();
+    assert_eq!(*parsed, Duration::from_millis(1500));
+
+    let parsed: SignedDuration = serde_json::from_str("-90").unwrap();
+    assert_eq!(parsed, SignedDuration::new(true, Duration::from_secs(90)));
+    assert!(serde_json::from_str::<SignedDuration>("1.5").is_err());
+
+    let value: serde_json::Value = serde_json::from_str("0.1").unwrap();
+    let parsed: TestStruct =
+        serde_json::from_value(serde_json::json!({ "duration": value })).unwrap();
+    assert_eq!(parsed.duration, Duration::from_millis(100));
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_deserialize_arbitrary_precision_chrono() {
+    #[derive(Debug, Deserialize)]
+    struct ChronoStruct {
+        #[serde(with = "serde_duration::chrono")]
+        duration: chrono::Duration,
+    }
+
+    let parsed: ChronoStruct = serde_json::from_str("{\"duration\":30}").unwrap();
+    assert_eq!(parsed.duration, chrono::Duration::seconds(30));
+    let parsed: ChronoStruct = serde_json::from_str("{\"duration\":-30}").unwrap();
+    assert_eq!(parsed.duration, chrono::Duration::seconds(-30));
+
+    let error = serde_json::from_str::<ChronoStruct>("{\"duration\":1.5}").unwrap_err();
+    assert!(
+        error.to_string().contains("invalid type: floating point `1.5`"),
+        "{:?} does not contain {:?}",
+        error.to_string(),
+        "invalid type: floating point `1.5`"
+    );
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_deserialize_arbitrary_precision_time() {
+    #[derive(Debug, Deserialize)]
+    struct TimeStruct {
+        #[serde(with = "serde_duration::time")]
+        duration: time::Duration,
+    }
+
+    let parsed: TimeStruct = serde_json::from_str("{\"duration\":30}").unwrap();
+    assert_eq!(parsed.duration, time::Duration::seconds(30));
+    let parsed: TimeStruct = serde_json::from_str("{\"duration\":-30}").unwrap();
+    assert_eq!(parsed.duration, time::Duration::seconds(-30));
+
+    let error = serde_json::from_str::<TimeStruct>("{\"duration\":1.5}").unwrap_err();
+    assert!(
+        error.to_string().contains("invalid type: floating point `1.5`"),
+        "{:?} does not contain {:?}",
+        error.to_string(),
+        "invalid type: floating point `1.5`"
+    );
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "chrono")]
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TestStruct {
+    #[serde(with = "serde_duration::chrono")]
+    duration: chrono::Duration,
+}
+
+#[test]
+fn test_serialize_chrono() {
+    let tests = vec![
+        (chrono::Duration::seconds(30), "{\"duration\":\"30s\"}"),
+        (chrono::Duration::seconds(-30), "{\"duration\":\"-30s\"}"),
+        (chrono::Duration::minutes(-90), "{\"duration\":\"-90m\"}"),
+        (
+            chrono::Duration::milliseconds(-1500),
+            "{\"duration\":\"-1500ms\"}",
+        ),
+        (chrono::Duration::zero(), "{\"duration\":\"0s\"}"),
+    ];
+
+    for (duration, expected) in tests {
+        let test_struct = TestStruct { duration };
+        let serialized = serde_json::to_string(&test_struct).unwrap();
+        assert_eq!(serialized, expected);
+    }
+}
+
+#[test]
+fn test_deserialize_chrono() {
+    let tests = vec![
+        ("{\"duration\":\"30s\"}", chrono::Duration::seconds(30)),
+        ("{\"duration\":\"-30s\"}", chrono::Duration::seconds(-30)),
+        (
+            "{\"duration\":\" -1h30m \"}",
+            chrono::Duration::minutes(-90),
+        ),
+        ("{\"duration\":-30}", chrono::Duration::seconds(-30)),
+    ];
+
+    for (json_str, expected) in tests {
+        let deserialized: TestStruct = serde_json::from_str(json_str)
+            .unwrap_or_else(|e| panic!("Failed to deserialize JSON: {}", e));
+        assert_eq!(deserialized.duration, expected);
+    }
+}
+
+#[test]
+fn test_deserialize_chrono_invalid() {
+    for json_str in [
+        "{\"duration\":\"--30s\"}",
+        "{\"duration\":\"-\"}",
+        "{\"duration\":\"-5x\"}",
+        "{\"duration\":\"100000000000000w\"}",
+    ] {
+        let result = serde_json::from_str::<TestStruct>(json_str);
+        assert!(result.is_err(), "{} should not deserialize", json_str);
+    }
+}
+
+#[test]
+fn test_parse_error_position_includes_sign() {
+    let json_str = "{\"duration\":\"-1h30x\"}";
+    let error = serde_json::from_str::<TestStruct>(json_str).unwrap_err();
+    assert!(
+        error.to_string().contains("unknown unit 'x' at position 5"),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn test_deserialize_chrono_repeated_sign() {
+    for input in ["--30s", "-+30s", "+-30s", "++30s"] {
+        let json_str = format!("{{\"duration\":\"{}\"}}", input);
+        let error = serde_json::from_str::<TestStruct>(&json_str).unwrap_err();
+        assert!(
+            error.to_string().contains("a duration may only have one sign"),
+            "{:?} does not contain {:?}",
+            error.to_string(),
+            "a duration may only have one sign"
+        );
+    }
+
+    let json_str = "{\"duration\":\"+30s\"}";
+    let deserialized: TestStruct = serde_json::from_str(json_str)
+        .unwrap_or_else(|e| panic!("Failed to deserialize JSON: {}", e));
+    assert_eq!(deserialized.duration, chrono::Duration::seconds(30));
+}
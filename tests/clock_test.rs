@@ -0,0 +1,125 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TestStruct {
+    #[serde(with = "serde_duration::clock")]
+    duration: Duration,
+}
+
+#[test]
+fn test_serialize_clock() {
+    let tests = vec![
+        (Duration::from_secs(5400), "{\"duration\":\"01:30:00\"}"),
+        (Duration::ZERO, "{\"duration\":\"00:00:00\"}"),
+        (Duration::from_secs(59), "{\"duration\":\"00:00:59\"}"),
+        (Duration::from_secs(360_000), "{\"duration\":\"100:00:00\"}"),
+        (Duration::from_millis(1500), "{\"duration\":\"00:00:01.5\"}"),
+    ];
+
+    for (duration, expected) in tests {
+        let serialized = serde_json::to_string(&TestStruct { duration }).unwrap();
+        assert_eq!(serialized, expected);
+    }
+}
+
+#[test]
+fn test_deserialize_clock() {
+    let tests = vec![
+        ("{\"duration\":\"01:30:00\"}", Duration::from_secs(5400)),
+        ("{\"duration\":\"1:30:00\"}", Duration::from_secs(5400)),
+        ("{\"duration\":\"05:30\"}", Duration::from_secs(330)),
+        // Two fields are always minutes and seconds, with unbounded minutes
+        ("{\"duration\":\"90:00\"}", Duration::from_secs(5400)),
+        ("{\"duration\":\"100:00:00\"}", Duration::from_secs(360_000)),
+        (
+            "{\"duration\":\"00:00:01.25\"}",
+            Duration::from_millis(1250),
+        ),
+        ("{\"duration\":\" 00:01:00 \"}", Duration::from_secs(60)),
+    ];
+
+    for (json_str, expected) in tests {
+        let deserialized: TestStruct = serde_json::from_str(json_str)
+            .unwrap_or_else(|e| panic!("Failed to deserialize {}: {}", json_str, e));
+        assert_eq!(deserialized.duration, expected);
+    }
+}
+
+#[test]
+fn test_deserialize_clock_out_of_range() {
+    let tests = vec![
+        ("00:99:00", "minutes must be between 0 and 59"),
+        ("00:00:60", "seconds must be between 0 and 59"),
+        ("90:75", "seconds must be between 0 and 59"),
+    ];
+
+    for (input, expected) in tests {
+        let json_str = format!("{{\"duration\":\"{}\"}}", input);
+        let error = serde_json::from_str::<TestStruct>(&json_str).unwrap_err();
+        assert!(
+            error.to_string().contains(expected),
+            "{:?} does not contain {:?}",
+            error.to_string(),
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_deserialize_clock_malformed() {
+    for input in [
+        "",
+        "30",
+        "1:2:3:4",
+        "01::00",
+        "01:3a:00",
+        "1.5:00",
+        "-1:00",
+        "01:30:00.",
+    ] {
+        let json_str = format!("{{\"duration\":\"{}\"}}", input);
+        let result = serde_json::from_str::<TestStruct>(&json_str);
+        assert!(result.is_err(), "{} should not deserialize", input);
+    }
+}
+
+#[test]
+fn test_format_clock_hour_options() {
+    use serde_duration::clock::format_clock;
+
+    let long = Duration::from_secs(100 * 3600 + 5 * 60 + 7);
+    let short = Duration::from_secs(30 * 60 + 5);
+    let tests = vec![
+        (long, 2, true, "100:05:07"),
+        (long, 2, false, "100:05:07"),
+        (long, 4, true, "0100:05:07"),
+        (short, 2, true, "00:30:05"),
+        (short, 1, true, "0:30:05"),
+        (short, 2, false, "30:05"),
+        (Duration::from_millis(500), 1, false, "00:00.5"),
+    ];
+
+    for (duration, hour_width, always_show_hours, expected) in tests {
+        assert_eq!(
+            format_clock(&duration, hour_width, always_show_hours),
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_format_clock_parses_back() {
+    use serde_duration::clock::format_clock;
+
+    for duration in [Duration::from_secs(1805), Duration::from_secs(360_000)] {
+        for (hour_width, always_show_hours) in [(1, true), (2, false)] {
+            let json_str = format!(
+                "{{\"duration\":\"{}\"}}",
+                format_clock(&duration, hour_width, always_show_hours)
+            );
+            let deserialized: TestStruct = serde_json::from_str(&json_str).unwrap();
+            assert_eq!(deserialized.duration, duration);
+        }
+    }
+}
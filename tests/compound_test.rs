@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TestStruct {
+    #[serde(with = "serde_duration::compound")]
+    duration: Duration,
+}
+
+#[test]
+fn test_serialize_compound() {
+    let tests = vec![
+        (Duration::ZERO, "{\"duration\":\"0s\"}"),
+        (Duration::from_secs(90), "{\"duration\":\"1m30s\"}"),
+        (Duration::from_secs(3661), "{\"duration\":\"1h1m1s\"}"),
+        (Duration::from_secs(8 * 86400 + 60), "{\"duration\":\"1w1d1m\"}"),
+    ];
+
+    for (duration, expected) in tests {
+        let test_struct = TestStruct { duration };
+        let serialized = serde_json::to_string(&test_struct).unwrap();
+        assert_eq!(serialized, expected);
+    }
+}
+
+#[test]
+fn test_round_trip_compound() {
+    for secs in [0, 1, 59, 61, 90, 3599, 3661, 86399, 90061, 1_000_000] {
+        let duration = Duration::from_secs(secs);
+        let serialized = serde_json::to_string(&TestStruct { duration }).unwrap();
+        let deserialized: TestStruct = serde_json::from_str(&serialized)
+            .unwrap_or_else(|e| panic!("Failed to deserialize {}: {}", serialized, e));
+        assert_eq!(deserialized.duration, duration);
+    }
+}
+
+#[test]
+fn test_round_trip_compound_subsecond() {
+    let tests = vec![
+        (Duration::from_millis(1500), "1s500ms"),
+        (Duration::from_micros(1_000_250), "1s250us"),
+        (Duration::new(61, 1_001_001), "1m1s1ms1us1ns"),
+        (Duration::from_nanos(999), "999ns"),
+    ];
+
+    for (duration, expected) in tests {
+        let serialized = serde_json::to_string(&TestStruct { duration }).unwrap();
+        assert_eq!(serialized, format!("{{\"duration\":\"{}\"}}", expected));
+        let deserialized: TestStruct = serde_json::from_str(&serialized)
+            .unwrap_or_else(|e| panic!("Failed to deserialize {}: {}", serialized, e));
+        assert_eq!(deserialized.duration, duration);
+    }
+}
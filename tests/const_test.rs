@@ -0,0 +1,80 @@
+use serde_duration::const_parse_duration;
+use std::time::Duration;
+
+const TIMEOUT: Duration = const_parse_duration("30s");
+const INTERVAL: Duration = const_parse_duration("1h30m");
+const BACKOFF: [Duration; 3] = [
+    const_parse_duration("100ms"),
+    const_parse_duration("1 s"),
+    const_parse_duration("2W 1D"),
+];
+
+#[test]
+fn test_const_parse_duration_in_const() {
+    assert_eq!(TIMEOUT, Duration::from_secs(30));
+    assert_eq!(INTERVAL, Duration::from_secs(5400));
+    assert_eq!(
+        BACKOFF,
+        [
+            Duration::from_millis(100),
+            Duration::from_secs(1),
+            Duration::from_secs(15 * 86400),
+        ]
+    );
+}
+
+#[test]
+fn test_const_parse_duration_matches_parse_duration() {
+    for input in [
+        "0",
+        " 0 ",
+        "0s",
+        "30s",
+        "5m",
+        "250us",
+        "250µs",
+        "999ns",
+        " 1h 30m 15s ",
+        "1w2d3h4m5s6ms7us8ns",
+        "18446744073709551615s",
+    ] {
+        assert_eq!(
+            const_parse_duration(input),
+            serde_duration::parse_duration(input).unwrap(),
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_const_parse_duration_invalid() {
+    let tests = vec![
+        ("", "empty duration"),
+        ("00", "missing or unknown unit"),
+        ("0 0", "missing or unknown unit"),
+        ("30", "missing or unknown unit"),
+        ("30x", "missing or unknown unit"),
+        ("1.5h", "missing or unknown unit"),
+        ("s", "expected a whole number"),
+        ("18446744073709551616s", "duration overflow"),
+    ];
+
+    for (input, expected) in tests {
+        let error = std::panic::catch_unwind(|| const_parse_duration(input)).unwrap_err();
+        let message = error.downcast_ref::<&str>().copied().unwrap_or_default();
+        assert!(
+            message.contains(expected),
+            "{:?} does not contain {:?}",
+            message,
+            expected
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "duration overflow")]
+fn test_const_parse_duration_digits_overflow() {
+    // Just past u128::MAX, which must not wrap around to 3ns.
+    const_parse_duration("340282366920938463463374607431768211459ns");
+}
@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+use serde_duration::default_unit::{Millis, Seconds};
+use serde_duration::DurationWithDefault;
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TestStruct {
+    timeout: DurationWithDefault<Seconds>,
+    interval: DurationWithDefault<Millis>,
+}
+
+#[test]
+fn test_deserialize_bare_numbers() {
+    let tests = vec![
+        (
+            r#"{"timeout": "30", "interval": "250"}"#,
+            Duration::from_secs(30),
+            Duration::from_millis(250),
+        ),
+        (
+            r#"{"timeout": "1.5", "interval": "0.5"}"#,
+            Duration::from_millis(1500),
+            Duration::from_micros(500),
+        ),
+        (
+            r#"{"timeout": "1m", "interval": "2s"}"#,
+            Duration::from_secs(60),
+            Duration::from_secs(2),
+        ),
+    ];
+
+    for (input, timeout, interval) in tests {
+        let parsed: TestStruct = serde_json::from_str(input)
+            .unwrap_or_else(|e| panic!("Failed to parse {}: {}", input, e));
+        assert_eq!(*parsed.timeout, timeout);
+        assert_eq!(*parsed.interval, interval);
+    }
+}
+
+#[test]
+fn test_serialize_with_unit() {
+    let value = TestStruct {
+        timeout: Duration::from_secs(30).into(),
+        interval: DurationWithDefault::new(Duration::from_millis(250)),
+    };
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(json, r#"{"timeout":"30s","interval":"250ms"}"#);
+    assert_eq!(serde_json::from_str::<TestStruct>(&json).unwrap(), value);
+}
+
+#[test]
+fn test_deserialize_invalid() {
+    let tests = vec![
+        (r#"{"timeout": "", "interval": "1"}"#, "empty duration"),
+        (r#"{"timeout": "1", "interval": "5x"}"#, "unknown unit 'x'"),
+    ];
+
+    for (input, expected) in tests {
+        let error = serde_json::from_str::<TestStruct>(input).unwrap_err();
+        assert!(
+            error.to_string().contains(expected),
+            "{:?} does not contain {:?}",
+            error.to_string(),
+            expected
+        );
+    }
+}
@@ -15,3 +15,407 @@ fn test_deserialize() {
         .unwrap_or_else(|e| panic!("Failed to deserialize JSON: {}", e));
     assert_eq!(deserialized.duration, Duration::from_secs(1234));
 }
+
+#[test]
+fn test_deserialize_millis() {
+    let json_str = json!({"duration": "500ms"}).to_string();
+    let deserialized: TestStruct = serde_json::from_str(&json_str)
+        .unwrap_or_else(|e| panic!("Failed to deserialize JSON: {}", e));
+    assert_eq!(deserialized.duration, Duration::from_millis(500));
+}
+
+#[test]
+fn test_deserialize_micros() {
+    for input in ["250us", "250µs"] {
+        let json_str = json!({ "duration": input }).to_string();
+        let deserialized: TestStruct = serde_json::from_str(&json_str)
+            .unwrap_or_else(|e| panic!("Failed to deserialize JSON: {}", e));
+        assert_eq!(deserialized.duration, Duration::from_micros(250));
+    }
+}
+
+#[test]
+fn test_deserialize_nanos() {
+    let json_str = json!({"duration": "750ns"}).to_string();
+    let deserialized: TestStruct = serde_json::from_str(&json_str)
+        .unwrap_or_else(|e| panic!("Failed to deserialize JSON: {}", e));
+    assert_eq!(deserialized.duration, Duration::from_nanos(750));
+}
+
+#[test]
+fn test_deserialize_days_and_weeks() {
+    let tests = vec![
+        ("30d", Duration::from_secs(30 * 86400)),
+        ("2w", Duration::from_secs(2 * 604800)),
+    ];
+
+    for (input, expected) in tests {
+        let json_str = json!({ "duration": input }).to_string();
+        let deserialized: TestStruct = serde_json::from_str(&json_str)
+            .unwrap_or_else(|e| panic!("Failed to deserialize JSON: {}", e));
+        assert_eq!(deserialized.duration, expected);
+    }
+}
+
+#[test]
+fn test_deserialize_compound() {
+    let tests = vec![
+        ("1h30m", Duration::from_secs(5400)),
+        ("90m", Duration::from_secs(5400)),
+        ("1h30m15s", Duration::from_secs(5415)),
+        ("1s500ms", Duration::from_millis(1500)),
+        ("1h1h", Duration::from_secs(7200)),
+    ];
+
+    for (input, expected) in tests {
+        let json_str = json!({ "duration": input }).to_string();
+        let deserialized: TestStruct = serde_json::from_str(&json_str)
+            .unwrap_or_else(|e| panic!("Failed to deserialize JSON: {}", e));
+        assert_eq!(deserialized.duration, expected);
+    }
+}
+
+#[test]
+fn test_deserialize_compound_malformed() {
+    for input in ["1h30", "1h30x", "1hm"] {
+        let json_str = json!({ "duration": input }).to_string();
+        let result = serde_json::from_str::<TestStruct>(&json_str);
+        assert!(result.is_err(), "{} should not deserialize", input);
+    }
+}
+
+#[test]
+fn test_deserialize_fractional() {
+    let tests = vec![
+        ("1.5h", Duration::from_secs(5400)),
+        ("0.25m", Duration::from_secs(15)),
+        ("2.5s", Duration::from_millis(2500)),
+        ("0.5s", Duration::from_millis(500)),
+        ("1.5ms", Duration::from_micros(1500)),
+        ("1.5h30m", Duration::from_secs(7200)),
+    ];
+
+    for (input, expected) in tests {
+        let json_str = json!({ "duration": input }).to_string();
+        let deserialized: TestStruct = serde_json::from_str(&json_str)
+            .unwrap_or_else(|e| panic!("Failed to deserialize JSON: {}", e));
+        assert_eq!(deserialized.duration, expected);
+    }
+}
+
+#[test]
+fn test_deserialize_fractional_malformed() {
+    for input in ["1.s", ".5s", "1.5.5s", "1..5s"] {
+        let json_str = json!({ "duration": input }).to_string();
+        let result = serde_json::from_str::<TestStruct>(&json_str);
+        assert!(result.is_err(), "{} should not deserialize", input);
+    }
+}
+
+#[test]
+fn test_deserialize_error_message() {
+    let tests = vec![
+        ("5x", "invalid duration \"5x\": unknown unit 'x'"),
+        (
+            "1h30",
+            "invalid duration \"1h30\": missing unit at position 4",
+        ),
+        ("1h30x", "invalid duration \"1h30x\": unknown unit 'x'"),
+        ("1.5.5s", "invalid duration \"1.5.5s\": invalid number \"1.5.5\""),
+    ];
+
+    for (input, expected) in tests {
+        let json_str = json!({ "duration": input }).to_string();
+        let error = serde_json::from_str::<TestStruct>(&json_str).unwrap_err();
+        assert!(
+            error.to_string().contains(expected),
+            "{:?} does not contain {:?}",
+            error.to_string(),
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_deserialize_integer_seconds() {
+    for json_str in [r#"{"duration":"30s"}"#, r#"{"duration":30}"#] {
+        let deserialized: TestStruct = serde_json::from_str(json_str)
+            .unwrap_or_else(|e| panic!("Failed to deserialize JSON: {}", e));
+        assert_eq!(deserialized.duration, Duration::from_secs(30));
+    }
+}
+
+#[test]
+fn test_deserialize_negative_integer() {
+    let result = serde_json::from_str::<TestStruct>(r#"{"duration":-30}"#);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_deserialize_float_seconds() {
+    let tests = vec![
+        (r#"{"duration":1.5}"#, Duration::from_millis(1500)),
+        (r#"{"duration":0.0}"#, Duration::ZERO),
+    ];
+
+    for (json_str, expected) in tests {
+        let deserialized: TestStruct = serde_json::from_str(json_str)
+            .unwrap_or_else(|e| panic!("Failed to deserialize JSON: {}", e));
+        assert_eq!(deserialized.duration, expected);
+    }
+}
+
+#[test]
+fn test_deserialize_invalid_float() {
+    for json_str in [r#"{"duration":-1.5}"#, r#"{"duration":1e40}"#] {
+        let result = serde_json::from_str::<TestStruct>(json_str);
+        assert!(result.is_err(), "{} should not deserialize", json_str);
+    }
+}
+
+#[test]
+fn test_deserialize_non_finite_float() {
+    use serde::de::value::{Error, F64Deserializer};
+    use serde::de::IntoDeserializer;
+
+    for secs in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 1e40, -0.5] {
+        let deserializer: F64Deserializer<Error> = secs.into_deserializer();
+        let error = serde_duration::deserialize(deserializer).unwrap_err();
+        assert!(
+            error.to_string().contains("invalid value: floating point"),
+            "{:?} does not contain {:?}",
+            error.to_string(),
+            "invalid value: floating point"
+        );
+    }
+}
+
+#[test]
+fn test_deserialize_case_insensitive() {
+    let tests = vec![
+        ("30S", Duration::from_secs(30)),
+        ("5M", Duration::from_secs(300)),
+        ("3H", Duration::from_secs(3 * 3600)),
+        ("1H30m", Duration::from_secs(5400)),
+        ("500Ms", Duration::from_millis(500)),
+        ("250µS", Duration::from_micros(250)),
+    ];
+
+    for (input, expected) in tests {
+        let json_str = json!({ "duration": input }).to_string();
+        let deserialized: TestStruct = serde_json::from_str(&json_str)
+            .unwrap_or_else(|e| panic!("Failed to deserialize JSON: {}", e));
+        assert_eq!(deserialized.duration, expected);
+    }
+}
+
+#[test]
+fn test_deserialize_surrounding_whitespace() {
+    let tests = vec![
+        (" 30s", Duration::from_secs(30)),
+        ("30s ", Duration::from_secs(30)),
+        ("  5m  ", Duration::from_secs(300)),
+        ("\t1h30m\n", Duration::from_secs(5400)),
+    ];
+
+    for (input, expected) in tests {
+        let json_str = json!({ "duration": input }).to_string();
+        let deserialized: TestStruct = serde_json::from_str(&json_str)
+            .unwrap_or_else(|e| panic!("Failed to deserialize JSON: {}", e));
+        assert_eq!(deserialized.duration, expected);
+    }
+}
+
+#[test]
+fn test_deserialize_inner_whitespace() {
+    let tests = vec![
+        ("30 s", Duration::from_secs(30)),
+        ("1 h", Duration::from_secs(3600)),
+        ("1h 30m", Duration::from_secs(5400)),
+        ("1 h 30 m", Duration::from_secs(5400)),
+        ("1.5  ms", Duration::from_micros(1500)),
+    ];
+
+    for (input, expected) in tests {
+        let json_str = json!({ "duration": input }).to_string();
+        let deserialized: TestStruct = serde_json::from_str(&json_str)
+            .unwrap_or_else(|e| panic!("Failed to deserialize JSON: {}", e));
+        assert_eq!(deserialized.duration, expected);
+    }
+}
+
+#[test]
+fn test_deserialize_whitespace_inside_token() {
+    for input in ["3 0s", "1 h 30", "5 m s"] {
+        let json_str = json!({ "duration": input }).to_string();
+        let result = serde_json::from_str::<TestStruct>(&json_str);
+        assert!(result.is_err(), "{} should not deserialize", input);
+    }
+}
+
+#[test]
+fn test_deserialize_negative_string() {
+    for input in ["-30s", " -1h30m", "-0s"] {
+        let json_str = json!({ "duration": input }).to_string();
+        let error = serde_json::from_str::<TestStruct>(&json_str).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("negative durations are not supported for std::time::Duration"),
+            "{} gave {}",
+            input,
+            error
+        );
+    }
+}
+
+#[test]
+fn test_deserialize_borrowed_and_owned() {
+    use serde::de::value::{BorrowedStrDeserializer, Error, StringDeserializer};
+
+    let borrowed = BorrowedStrDeserializer::<Error>::new("1h30m");
+    assert_eq!(
+        serde_duration::deserialize(borrowed).unwrap(),
+        Duration::from_secs(5400)
+    );
+    let owned = StringDeserializer::<Error>::new("1h30m".to_string());
+    assert_eq!(
+        serde_duration::deserialize(owned).unwrap(),
+        Duration::from_secs(5400)
+    );
+
+    // An escape sequence makes serde_json decode the string into a buffer
+    // instead of borrowing it from the input.
+    let escaped: TestStruct = serde_json::from_str(r#"{"duration":"\u0033\u0030s"}"#).unwrap();
+    assert_eq!(escaped.duration, Duration::from_secs(30));
+    let from_value: TestStruct = serde_json::from_value(json!({ "duration": "30s" })).unwrap();
+    assert_eq!(from_value.duration, Duration::from_secs(30));
+    let from_reader: TestStruct = serde_json::from_reader(&b"{\"duration\":\"30s\"}"[..]).unwrap();
+    assert_eq!(from_reader.duration, Duration::from_secs(30));
+}
+
+#[test]
+fn test_deserialize_strings_and_numbers_uniformly() {
+    #[derive(Debug, Deserialize)]
+    struct Config {
+        #[serde(with = "serde_duration")]
+        string: Duration,
+        #[serde(with = "serde_duration")]
+        integer: Duration,
+        #[serde(with = "serde_duration")]
+        float: Duration,
+    }
+
+    let config: Config =
+        serde_json::from_str(r#"{"string": "30s", "integer": 30, "float": 1.5}"#).unwrap();
+    assert_eq!(config.string, Duration::from_secs(30));
+    assert_eq!(config.integer, Duration::from_secs(30));
+    assert_eq!(config.float, Duration::from_millis(1500));
+}
+
+#[test]
+fn test_deserialize_every_numeric_type() {
+    use serde::de::value::{
+        Error, F32Deserializer, F64Deserializer, I32Deserializer, I64Deserializer, StrDeserializer,
+        U32Deserializer, U64Deserializer, U8Deserializer,
+    };
+
+    let thirty = Duration::from_secs(30);
+    assert_eq!(
+        serde_duration::deserialize(U8Deserializer::<Error>::new(30)).unwrap(),
+        thirty
+    );
+    assert_eq!(
+        serde_duration::deserialize(U32Deserializer::<Error>::new(30)).unwrap(),
+        thirty
+    );
+    assert_eq!(
+        serde_duration::deserialize(U64Deserializer::<Error>::new(30)).unwrap(),
+        thirty
+    );
+    assert_eq!(
+        serde_duration::deserialize(I32Deserializer::<Error>::new(30)).unwrap(),
+        thirty
+    );
+    assert_eq!(
+        serde_duration::deserialize(I64Deserializer::<Error>::new(30)).unwrap(),
+        thirty
+    );
+    assert_eq!(
+        serde_duration::deserialize(F32Deserializer::<Error>::new(0.5)).unwrap(),
+        Duration::from_millis(500)
+    );
+    assert_eq!(
+        serde_duration::deserialize(F64Deserializer::<Error>::new(30.0)).unwrap(),
+        thirty
+    );
+    assert_eq!(
+        serde_duration::deserialize(StrDeserializer::<Error>::new("30s")).unwrap(),
+        thirty
+    );
+    assert!(serde_duration::deserialize(I32Deserializer::<Error>::new(-30)).is_err());
+    assert!(serde_duration::deserialize(F64Deserializer::<Error>::new(f64::NAN)).is_err());
+}
+
+#[test]
+fn test_deserialize_bare_zero() {
+    let deserialized: TestStruct = serde_json::from_str(r#"{"duration":"0"}"#)
+        .unwrap_or_else(|e| panic!("Failed to deserialize JSON: {}", e));
+    assert_eq!(deserialized.duration, Duration::ZERO);
+}
+
+#[test]
+fn test_deserialize_quoted_number() {
+    let tests = vec![
+        ("30", Duration::from_secs(30)),
+        (" 1.5 ", Duration::from_millis(1500)),
+        ("+10", Duration::from_secs(10)),
+        ("1_000", Duration::from_secs(1000)),
+    ];
+
+    for (input, expected) in tests {
+        let json_str = json!({ "duration": input }).to_string();
+        let deserialized: TestStruct = serde_json::from_str(&json_str)
+            .unwrap_or_else(|e| panic!("Failed to deserialize JSON: {}", e));
+        assert_eq!(deserialized.duration, expected, "{}", input);
+    }
+
+    for input in ["-30", "1.2.3", "30 30"] {
+        let json_str = json!({ "duration": input }).to_string();
+        let result = serde_json::from_str::<TestStruct>(&json_str);
+        assert!(result.is_err(), "{} should not deserialize", input);
+    }
+}
+
+#[test]
+fn test_deserialize_fractional_matches_integer() {
+    let tests = vec![
+        (
+            vec!["1.5h", "90m", "5400s", "1h30m", "0.0625d"],
+            Duration::from_secs(5400),
+        ),
+        (vec!["0.1h", "6m", "360s"], Duration::from_secs(360)),
+        (
+            vec!["1.001s", "1001ms", "1001000us"],
+            Duration::from_millis(1001),
+        ),
+        (
+            vec!["0.000001s", "0.001ms", "1us", "1000ns"],
+            Duration::from_micros(1),
+        ),
+        (vec!["0.3s", "300ms"], Duration::from_millis(300)),
+        (
+            vec!["2.5w", "17.5d", "420h"],
+            Duration::from_secs(1_512_000),
+        ),
+    ];
+
+    for (inputs, expected) in tests {
+        for input in inputs {
+            let json_str = json!({ "duration": input }).to_string();
+            let deserialized: TestStruct = serde_json::from_str(&json_str)
+                .unwrap_or_else(|e| panic!("Failed to deserialize JSON: {}", e));
+            assert_eq!(deserialized.duration, expected, "{}", input);
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use serde::{Deserialize, Serialize};
+use serde_duration::DurationStr;
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug)]
+struct TestStruct {
+    duration: DurationStr,
+}
+
+#[test]
+fn test_round_trip_keeps_original() {
+    let tests = vec![
+        ("60s", Duration::from_secs(60)),
+        ("1h 30m", Duration::from_secs(5400)),
+        ("90 minutes", Duration::from_secs(5400)),
+        ("1000ms", Duration::from_secs(1)),
+        ("+1.5H", Duration::from_secs(5400)),
+    ];
+
+    for (input, expected) in tests {
+        let json = format!(r#"{{"duration":"{}"}}"#, input);
+        let parsed: TestStruct = serde_json::from_str(&json)
+            .unwrap_or_else(|e| panic!("Failed to parse {}: {}", json, e));
+        assert_eq!(*parsed.duration, expected);
+        assert_eq!(parsed.duration.original(), Some(input));
+        assert_eq!(serde_json::to_string(&parsed).unwrap(), json);
+        assert_eq!(parsed.duration.to_string(), input);
+    }
+}
+
+#[test]
+fn test_changed_duration_is_reformatted() {
+    let mut parsed: TestStruct = serde_json::from_str(r#"{"duration":"60s"}"#).unwrap();
+    parsed.duration.set(Duration::from_secs(60));
+    assert_eq!(
+        serde_json::to_string(&parsed).unwrap(),
+        r#"{"duration":"60s"}"#
+    );
+
+    parsed.duration.set(Duration::from_secs(120));
+    assert_eq!(parsed.duration.original(), None);
+    assert_eq!(
+        serde_json::to_string(&parsed).unwrap(),
+        r#"{"duration":"2m"}"#
+    );
+}
+
+#[test]
+fn test_new_and_numbers_have_no_original() {
+    let value = TestStruct {
+        duration: Duration::from_secs(60).into(),
+    };
+    assert_eq!(
+        serde_json::to_string(&value).unwrap(),
+        r#"{"duration":"1m"}"#
+    );
+
+    let parsed: TestStruct = serde_json::from_str(r#"{"duration":60}"#).unwrap();
+    assert_eq!(*parsed.duration, Duration::from_secs(60));
+    assert_eq!(parsed.duration.original(), None);
+    assert_eq!(
+        serde_json::to_string(&parsed).unwrap(),
+        r#"{"duration":"1m"}"#
+    );
+}
+
+#[test]
+fn test_equality_ignores_original() {
+    let a: DurationStr = "60s".parse().unwrap();
+    let b: DurationStr = "1m".parse().unwrap();
+    assert_eq!(a, b);
+    assert_eq!(a, DurationStr::new(Duration::from_secs(60)));
+}
+
+#[test]
+fn test_deserialize_invalid() {
+    let tests = vec![
+        (r#"{"duration":"5x"}"#, "unknown unit 'x'"),
+        (r#"{"duration":-1}"#, "invalid value"),
+    ];
+
+    for (input, expected) in tests {
+        let error = serde_json::from_str::<TestStruct>(input).unwrap_err();
+        assert!(
+            error.to_string().contains(expected),
+            "{:?} does not contain {:?}",
+            error.to_string(),
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_conversions() {
+    let parsed = DurationStr::try_from("60s").unwrap();
+    assert_eq!(*parsed, Duration::from_secs(60));
+    assert_eq!(String::from(parsed.clone()), "60s");
+    assert_eq!(Duration::from(parsed), Duration::from_secs(60));
+    let error = DurationStr::try_from("60x").unwrap_err();
+    assert!(error.to_string().contains("unknown unit"));
+
+    let wrapped: DurationStr = Duration::from_secs(60).into();
+    let s: String = wrapped.into();
+    assert_eq!(s, "1m");
+}
+
+#[test]
+fn test_deserialize_quoted_number() {
+    let parsed: DurationStr = serde_json::from_str(r#""30""#).unwrap();
+    assert_eq!(*parsed, Duration::from_secs(30));
+    assert_eq!(parsed.original(), Some("30"));
+    assert_eq!(serde_json::to_string(&parsed).unwrap(), r#""30""#);
+}
+
+#[test]
+fn test_from_str_matches_deserialize() {
+    for input in ["30", "30s", "1h30m", " 90 "] {
+        let parsed: DurationStr = input.parse().unwrap();
+        let deserialized: DurationStr =
+            serde_json::from_str(&format!("{:?}", input)).unwrap();
+        assert_eq!(*parsed, *deserialized, "{}", input);
+        assert_eq!(parsed.original(), deserialized.original(), "{}", input);
+    }
+    assert_eq!(*"30".parse::<DurationStr>().unwrap(), Duration::from_secs(30));
+}
@@ -0,0 +1,68 @@
+use serde_duration::{DurationString, InvalidDurationError};
+use std::time::Duration;
+
+#[test]
+fn test_parse_duration_string() {
+    let tests = vec![
+        ("30s", Duration::from_secs(30)),
+        ("1h30m", Duration::from_secs(5400)),
+        ("250ms", Duration::from_millis(250)),
+    ];
+
+    for (input, expected) in tests {
+        let parsed: DurationString = input
+            .parse()
+            .unwrap_or_else(|e| panic!("Failed to parse {}: {}", input, e));
+        assert_eq!(parsed, DurationString(expected));
+        assert_eq!(*parsed, expected);
+    }
+}
+
+#[test]
+fn test_parse_duration_string_invalid() {
+    for input in ["", "30", "5x"] {
+        let result: Result<DurationString, InvalidDurationError> = input.parse();
+        assert!(result.is_err(), "{} should not parse", input);
+    }
+}
+
+#[test]
+fn test_display_duration_string() {
+    let tests = vec![
+        (Duration::from_secs(90), "90s"),
+        (Duration::from_secs(3600), "1h"),
+        (Duration::from_millis(1500), "1500ms"),
+    ];
+
+    for (duration, expected) in tests {
+        assert_eq!(format!("{}", DurationString(duration)), expected);
+        assert_eq!(
+            DurationString(duration).to_string(),
+            serde_duration::format_duration(&duration)
+        );
+    }
+}
+
+#[test]
+fn test_round_trip_duration_string() {
+    for secs in [0, 1, 59, 61, 90, 3600, 3661, 86400, 604800] {
+        let duration = DurationString(Duration::from_secs(secs));
+        let parsed: DurationString = duration.to_string().parse().unwrap();
+        assert_eq!(parsed, duration);
+    }
+}
+
+#[test]
+fn test_duration_string_conversions() {
+    let parsed = DurationString::try_from("1h30m").unwrap();
+    assert_eq!(parsed, DurationString(Duration::from_secs(5400)));
+    let error = DurationString::try_from("5x").unwrap_err();
+    assert!(error.to_string().contains("unknown unit 'x'"));
+
+    let wrapped: DurationString = Duration::from_secs(90).into();
+    assert_eq!(wrapped, DurationString(Duration::from_secs(90)));
+    assert_eq!(Duration::from(wrapped), Duration::from_secs(90));
+    assert_eq!(String::from(wrapped), "90s");
+    let s: String = DurationString(Duration::from_secs(3600)).into();
+    assert_eq!(s, "1h");
+}
@@ -0,0 +1,469 @@
+use serde::{Deserialize, Serialize};
+use serde_duration::{
+    format_duration, format_duration_as, format_duration_as_rounded, format_duration_cow,
+    format_duration_exact, format_duration_pretty, format_duration_rounded, normalize_duration_str,
+    write_duration, DurationFormat, RoundingMode, Unit,
+};
+use std::borrow::Cow;
+use std::time::Duration;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TestStruct {
+    #[serde(with = "serde_duration")]
+    duration: Duration,
+}
+
+#[test]
+fn test_format_duration() {
+    let tests = vec![
+        (Duration::from_secs(90), "90s"),
+        (Duration::from_secs(3600), "1h"),
+        (Duration::from_millis(1500), "1500ms"),
+        (Duration::ZERO, "0s"),
+    ];
+
+    for (duration, expected) in tests {
+        assert_eq!(format_duration(&duration), expected);
+    }
+}
+
+#[test]
+fn test_format_duration_cow() {
+    let borrowed = vec![
+        (Duration::ZERO, "0s"),
+        (Duration::from_millis(500), "500ms"),
+        (Duration::from_secs(1), "1s"),
+        (Duration::from_secs(30), "30s"),
+        (Duration::from_secs(60), "1m"),
+        (Duration::from_secs(3600), "1h"),
+        (Duration::from_secs(604_800), "1w"),
+    ];
+    for (duration, expected) in borrowed {
+        let s = format_duration_cow(&duration);
+        assert!(matches!(s, Cow::Borrowed(_)), "{:?} was not borrowed", s);
+        assert_eq!(s, expected);
+        assert_eq!(s, format_duration(&duration));
+    }
+
+    for duration in [
+        Duration::from_secs(31),
+        Duration::from_millis(1500),
+        Duration::new(30, 1),
+        Duration::MAX,
+    ] {
+        let s = format_duration_cow(&duration);
+        assert!(matches!(s, Cow::Owned(_)), "{:?} was not owned", s);
+        assert_eq!(s, format_duration(&duration));
+    }
+}
+
+#[test]
+fn test_format_duration_subsecond() {
+    let tests = vec![
+        (Duration::from_millis(500), "500ms"),
+        (Duration::from_micros(500), "500us"),
+        (Duration::from_nanos(500), "500ns"),
+        (Duration::from_micros(1500), "1500us"),
+        (Duration::from_nanos(1_000_500), "1000500ns"),
+        (Duration::from_millis(2000), "2s"),
+    ];
+
+    for (duration, expected) in tests {
+        assert_eq!(format_duration(&duration), expected);
+    }
+}
+
+#[test]
+fn test_format_duration_matches_serialize() {
+    for duration in [
+        Duration::from_secs(61),
+        Duration::from_secs(86400),
+        Duration::from_nanos(750),
+    ] {
+        let serialized = serde_json::to_string(&TestStruct { duration }).unwrap();
+        assert_eq!(serialized, format!("{{\"duration\":\"{}\"}}", format_duration(&duration)));
+    }
+}
+
+#[test]
+fn test_format_duration_as() {
+    let duration = Duration::from_secs(7200);
+    let tests = vec![
+        (Unit::Seconds, "7200s"),
+        (Unit::Minutes, "120m"),
+        (Unit::Hours, "2h"),
+        (Unit::Millis, "7200000ms"),
+    ];
+
+    for (unit, expected) in tests {
+        assert_eq!(
+            format_duration_as(&duration, unit, false).unwrap(),
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_format_duration_as_inexact() {
+    let duration = Duration::from_secs(5400);
+    assert_eq!(format_duration_as(&duration, Unit::Hours, false), None);
+    assert_eq!(
+        format_duration_as(&duration, Unit::Hours, true).unwrap(),
+        "1h"
+    );
+    assert_eq!(
+        format_duration_as(&duration, Unit::Days, true).unwrap(),
+        "0s"
+    );
+    assert_eq!(
+        format_duration_as(&Duration::from_millis(1500), Unit::Seconds, true).unwrap(),
+        "1s"
+    );
+}
+
+#[test]
+fn test_format_duration_as_rounded() {
+    let duration = Duration::from_secs(5400);
+    let tests = vec![
+        (None, None),
+        (Some(RoundingMode::Truncate), Some("1h")),
+        (Some(RoundingMode::Round), Some("2h")),
+        (Some(RoundingMode::Ceil), Some("2h")),
+    ];
+    for (rounding, expected) in tests {
+        assert_eq!(
+            format_duration_as_rounded(&duration, Unit::Hours, rounding).as_deref(),
+            expected,
+            "{:?}",
+            rounding
+        );
+    }
+
+    let tests = vec![
+        (Duration::from_secs(7199), RoundingMode::Round, "2h"),
+        (Duration::from_secs(5399), RoundingMode::Round, "1h"),
+        (Duration::from_secs(3601), RoundingMode::Ceil, "2h"),
+        (Duration::from_secs(1000), RoundingMode::Truncate, "0s"),
+        (Duration::from_secs(1800), RoundingMode::Truncate, "0s"),
+        (Duration::from_secs(7200), RoundingMode::Ceil, "2h"),
+        (Duration::ZERO, RoundingMode::Ceil, "0s"),
+    ];
+    for (duration, rounding, expected) in tests {
+        assert_eq!(
+            format_duration_as_rounded(&duration, Unit::Hours, Some(rounding)).unwrap(),
+            expected,
+            "{:?} {:?}",
+            duration,
+            rounding
+        );
+    }
+    assert_eq!(
+        format_duration_as_rounded(&Duration::from_secs(7200), Unit::Hours, None).unwrap(),
+        "2h"
+    );
+}
+
+#[test]
+fn test_format_duration_rounded() {
+    let tests = vec![
+        (89, RoundingMode::Truncate, "1m"),
+        (89, RoundingMode::Round, "1m"),
+        (89, RoundingMode::Ceil, "2m"),
+        (90, RoundingMode::Truncate, "1m"),
+        (90, RoundingMode::Round, "2m"),
+        (90, RoundingMode::Ceil, "2m"),
+        (120, RoundingMode::Ceil, "2m"),
+        (59, RoundingMode::Round, "59s"),
+        (5399, RoundingMode::Round, "1h"),
+        (5400, RoundingMode::Round, "2h"),
+        (0, RoundingMode::Ceil, "0s"),
+    ];
+
+    for (secs, mode, expected) in tests {
+        let duration = Duration::from_secs(secs);
+        assert_eq!(
+            format_duration_rounded(&duration, mode),
+            expected,
+            "{}s with {:?}",
+            secs,
+            mode
+        );
+    }
+}
+
+#[test]
+fn test_format_duration_rounded_into_larger_unit() {
+    let tests = vec![
+        (Duration::from_millis(59_500), RoundingMode::Round, "1m"),
+        (Duration::from_millis(59_500), RoundingMode::Ceil, "1m"),
+        (Duration::from_millis(59_500), RoundingMode::Truncate, "59s"),
+        (Duration::from_millis(59_600), RoundingMode::Round, "1m"),
+        (Duration::from_millis(59_400), RoundingMode::Round, "59s"),
+        (Duration::from_millis(3_599_500), RoundingMode::Round, "1h"),
+        (Duration::from_millis(3_599_500), RoundingMode::Ceil, "1h"),
+        (Duration::from_millis(3_599_500), RoundingMode::Truncate, "59m"),
+        (Duration::from_nanos(999_999_500), RoundingMode::Round, "1s"),
+        (Duration::from_secs(30), RoundingMode::Ceil, "30s"),
+        (Duration::from_secs(604_799), RoundingMode::Ceil, "1w"),
+    ];
+
+    for (duration, mode, expected) in tests {
+        assert_eq!(
+            format_duration_rounded(&duration, mode),
+            expected,
+            "{:?} with {:?}",
+            duration,
+            mode
+        );
+    }
+}
+
+#[test]
+fn test_format_duration_rounded_subsecond() {
+    let duration = Duration::from_micros(1500);
+    assert_eq!(
+        format_duration_rounded(&duration, RoundingMode::Truncate),
+        "1ms"
+    );
+    assert_eq!(
+        format_duration_rounded(&duration, RoundingMode::Round),
+        "2ms"
+    );
+}
+
+#[test]
+fn test_format_zero_is_canonical() {
+    for unit in [Unit::Nanos, Unit::Millis, Unit::Hours, Unit::Weeks] {
+        for truncate in [false, true] {
+            assert_eq!(
+                format_duration_as(&Duration::ZERO, unit, truncate).unwrap(),
+                "0s"
+            );
+        }
+    }
+    for mode in [
+        RoundingMode::Truncate,
+        RoundingMode::Round,
+        RoundingMode::Ceil,
+    ] {
+        assert_eq!(format_duration_rounded(&Duration::ZERO, mode), "0s");
+    }
+    assert_eq!(
+        serde_duration::format_duration_compound(&Duration::ZERO),
+        "0s"
+    );
+}
+
+#[test]
+fn test_format_months_and_years_only_on_request() {
+    let year = Duration::from_secs(31_536_000);
+    let month = Duration::from_secs(2_592_000);
+    assert_eq!(format_duration(&year), "365d");
+    assert_eq!(format_duration(&month), "30d");
+    assert_eq!(format_duration_rounded(&year, RoundingMode::Round), "52w");
+    assert_eq!(format_duration_as(&year, Unit::Years, false).unwrap(), "1y");
+    assert_eq!(
+        format_duration_as(&month, Unit::Months, false).unwrap(),
+        "1mo"
+    );
+    assert_eq!(format_duration_as(&year, Unit::Months, false), None);
+}
+
+#[test]
+fn test_format_duration_exact_is_format_duration() {
+    for duration in [
+        Duration::from_secs(121),
+        Duration::from_secs(7260),
+        Duration::from_millis(60_500),
+        Duration::ZERO,
+    ] {
+        assert_eq!(format_duration_exact(&duration), format_duration(&duration));
+    }
+}
+
+#[test]
+fn test_duration_format_default_matches_format_duration() {
+    for duration in [
+        Duration::ZERO,
+        Duration::from_secs(90),
+        Duration::from_secs(7200),
+        Duration::from_secs(1_209_600),
+        Duration::from_millis(1500),
+        Duration::new(1, 1),
+        Duration::MAX,
+    ] {
+        assert_eq!(
+            DurationFormat::new().format(&duration),
+            format_duration(&duration)
+        );
+    }
+}
+
+#[test]
+fn test_duration_format_compound() {
+    let format = DurationFormat::new()
+        .compound(true)
+        .smallest_unit(Unit::Millis);
+    let tests = vec![
+        (Duration::from_secs(5400), "1h30m"),
+        (Duration::new(3661, 250_999_999), "1h1m1s250ms"),
+        (Duration::from_micros(999), "0s"),
+        (Duration::from_secs(694_861), "1w1d1h1m1s"),
+    ];
+    for (duration, expected) in tests {
+        assert_eq!(format.format(&duration), expected);
+    }
+
+    let format = format.largest_unit(Unit::Hours);
+    assert_eq!(format.format(&Duration::from_secs(694_861)), "193h1m1s");
+    let format = format.rounding(RoundingMode::Round);
+    assert_eq!(format.format(&Duration::from_micros(1_500)), "2ms");
+}
+
+#[test]
+fn test_duration_format_single_unit() {
+    let tests = vec![
+        (DurationFormat::new().unit(Unit::Seconds), "5400s"),
+        (DurationFormat::new().unit(Unit::Hours), "1h"),
+        (
+            DurationFormat::new()
+                .unit(Unit::Hours)
+                .rounding(RoundingMode::Round),
+            "2h",
+        ),
+        (DurationFormat::new().smallest_unit(Unit::Minutes), "90m"),
+        (
+            DurationFormat::new()
+                .smallest_unit(Unit::Hours)
+                .rounding(RoundingMode::Ceil),
+            "2h",
+        ),
+    ];
+    let duration = Duration::new(5400, 1);
+    for (format, expected) in tests {
+        assert_eq!(format.format(&duration), expected, "{:?}", format);
+    }
+
+    let year = Duration::from_secs(365 * 86400);
+    assert_eq!(DurationFormat::new().format(&year), "365d");
+    assert_eq!(
+        DurationFormat::new()
+            .largest_unit(Unit::Years)
+            .format(&year),
+        "1y"
+    );
+    assert_eq!(
+        DurationFormat::new()
+            .unit(Unit::Millis)
+            .format(&Duration::ZERO),
+        "0s"
+    );
+    // A duration that truncates to zero of the unit is still zero.
+    assert_eq!(
+        DurationFormat::new()
+            .unit(Unit::Hours)
+            .format(&Duration::from_secs(1800)),
+        "0s"
+    );
+}
+
+#[test]
+fn test_format_duration_pretty() {
+    let tests = vec![
+        (Duration::from_secs(5415), 3, "1 hour 30 minutes 15 seconds"),
+        (Duration::from_secs(5415), 2, "1 hour 30 minutes"),
+        (Duration::from_secs(5415), 1, "1 hour"),
+        (Duration::from_secs(5415), 0, "1 hour"),
+        (
+            Duration::from_secs(5415),
+            10,
+            "1 hour 30 minutes 15 seconds",
+        ),
+        (Duration::from_secs(3615), 2, "1 hour"),
+        (Duration::from_secs(3615), 3, "1 hour 15 seconds"),
+        (Duration::from_secs(694_861), 2, "1 week 1 day"),
+        (Duration::from_millis(1500), 2, "1 second 500 milliseconds"),
+        (Duration::from_nanos(1), 2, "1 nanosecond"),
+        (Duration::ZERO, 2, "0 seconds"),
+    ];
+
+    for (duration, max_components, expected) in tests {
+        assert_eq!(format_duration_pretty(&duration, max_components), expected);
+    }
+}
+
+#[test]
+fn test_format_duration_pretty_plurals() {
+    let tests = vec![
+        (Duration::from_secs(1), "1 second"),
+        (Duration::from_secs(2), "2 seconds"),
+        (Duration::from_secs(60), "1 minute"),
+        (Duration::from_secs(120), "2 minutes"),
+        (Duration::from_secs(3600), "1 hour"),
+        (Duration::from_secs(7200), "2 hours"),
+        (Duration::from_secs(86400), "1 day"),
+        (Duration::from_secs(172_800), "2 days"),
+        (Duration::from_secs(1_209_600), "2 weeks"),
+        (Duration::from_millis(1), "1 millisecond"),
+        (Duration::from_micros(2), "2 microseconds"),
+        (Duration::from_secs(7260), "2 hours 1 minute"),
+    ];
+
+    for (duration, expected) in tests {
+        assert_eq!(format_duration_pretty(&duration, 2), expected);
+    }
+}
+
+#[test]
+fn test_normalize_duration_str() {
+    let tests = vec![
+        ("60s", "1m"),
+        ("120s", "2m"),
+        ("61s", "61s"),
+        ("1h0m0s", "1h"),
+        ("1h 30 minutes", "90m"),
+        ("1.5h", "90m"),
+        ("0.5s", "500ms"),
+        ("1000ms", "1s"),
+        ("+7d", "1w"),
+        (" 3600S ", "1h"),
+        ("0m", "0s"),
+        ("1m", "1m"),
+    ];
+
+    for (input, expected) in tests {
+        let normalized = normalize_duration_str(input)
+            .unwrap_or_else(|e| panic!("Failed to parse {}: {}", input, e));
+        assert_eq!(normalized, expected, "{}", input);
+        assert_eq!(normalize_duration_str(&normalized).unwrap(), normalized);
+    }
+
+    assert!(normalize_duration_str("30x").is_err());
+    assert!(normalize_duration_str("").is_err());
+}
+
+#[test]
+fn test_write_duration() {
+    let durations = [
+        Duration::from_secs(90),
+        Duration::from_secs(3600),
+        Duration::from_millis(1500),
+        Duration::ZERO,
+        Duration::MAX,
+    ];
+
+    let mut buffer = String::new();
+    for (i, duration) in durations.iter().enumerate() {
+        if i > 0 {
+            buffer.push(',');
+        }
+        write_duration(duration, &mut buffer).unwrap();
+    }
+    assert_eq!(buffer, "90s,1h,1500ms,0s,18446744073709551615999999999ns");
+
+    for duration in durations {
+        let mut s = String::new();
+        write_duration(&duration, &mut s).unwrap();
+        assert_eq!(s, format_duration(&duration));
+    }
+}
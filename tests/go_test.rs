@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TestStruct {
+    #[serde(with = "serde_duration::go")]
+    duration: Duration,
+}
+
+#[test]
+fn test_serialize_go() {
+    let tests = vec![
+        (Duration::ZERO, "0s"),
+        (Duration::from_nanos(999), "999ns"),
+        (Duration::from_nanos(1500), "1.5µs"),
+        (Duration::from_micros(500), "500µs"),
+        (Duration::from_micros(1500), "1.5ms"),
+        (Duration::from_millis(1500), "1.5s"),
+        (Duration::from_secs(90), "1m30s"),
+        (Duration::from_secs(3600), "1h0m0s"),
+        (Duration::from_secs(5400), "1h30m0s"),
+        (Duration::from_millis(259_380_500), "72h3m0.5s"),
+    ];
+
+    for (duration, expected) in tests {
+        let test_struct = TestStruct { duration };
+        let serialized = serde_json::to_string(&test_struct).unwrap();
+        assert_eq!(serialized, format!("{{\"duration\":\"{}\"}}", expected));
+    }
+}
+
+#[test]
+fn test_deserialize_go() {
+    let tests = vec![
+        ("0", Duration::ZERO),
+        ("0s", Duration::ZERO),
+        ("1h30m0s", Duration::from_secs(5400)),
+        ("1500ms", Duration::from_millis(1500)),
+        ("2h45m", Duration::from_secs(9900)),
+        ("1.5h", Duration::from_secs(5400)),
+        ("+5s", Duration::from_secs(5)),
+        (".5s", Duration::from_millis(500)),
+        ("5.s", Duration::from_secs(5)),
+        ("1µs", Duration::from_micros(1)),
+        ("1μs", Duration::from_micros(1)),
+        ("1us", Duration::from_micros(1)),
+        ("72h3m0.5s", Duration::from_millis(259_380_500)),
+    ];
+
+    for (input, expected) in tests {
+        let json_str = format!("{{\"duration\":\"{}\"}}", input);
+        let deserialized: TestStruct = serde_json::from_str(&json_str)
+            .unwrap_or_else(|e| panic!("Failed to deserialize {}: {}", input, e));
+        assert_eq!(deserialized.duration, expected);
+    }
+}
+
+#[test]
+fn test_deserialize_go_invalid() {
+    for input in ["", "1", "-1.5h", "1H", "1 s", "1d", ".s", "+", "1.5.5s"] {
+        let json_str = format!("{{\"duration\":\"{}\"}}", input);
+        let result = serde_json::from_str::<TestStruct>(&json_str);
+        assert!(result.is_err(), "{} should not deserialize", input);
+    }
+}
+
+#[test]
+fn test_deserialize_go_missing_value() {
+    for (input, suffix) in [("h", "h"), (".s", "s"), ("1h.m", "m")] {
+        let json_str = format!("{{\"duration\":\"{}\"}}", input);
+        let error = serde_json::from_str::<TestStruct>(&json_str).unwrap_err();
+        let expected = format!("missing numeric value before unit '{}'", suffix);
+        assert!(
+            error.to_string().contains(&expected),
+            "{:?} does not contain {:?}",
+            error.to_string(),
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_round_trip_go() {
+    for nanos in [1, 999, 1_500, 1_000_000, 1_500_000_000, 5_400_000_000_000] {
+        let duration = Duration::from_nanos(nanos);
+        let serialized = serde_json::to_string(&TestStruct { duration }).unwrap();
+        let deserialized: TestStruct = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.duration, duration);
+    }
+}
+
+#[test]
+fn test_deserialize_go_borrowed_and_owned() {
+    use serde::de::value::{BorrowedStrDeserializer, Error, StringDeserializer};
+
+    let borrowed = BorrowedStrDeserializer::<Error>::new("1h30m0s");
+    assert_eq!(
+        serde_duration::go::deserialize(borrowed).unwrap(),
+        Duration::from_secs(5400)
+    );
+    let owned = StringDeserializer::<Error>::new("1h30m0s".to_string());
+    assert_eq!(
+        serde_duration::go::deserialize(owned).unwrap(),
+        Duration::from_secs(5400)
+    );
+}
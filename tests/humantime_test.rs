@@ -0,0 +1,93 @@
+#![cfg(feature = "humantime")]
+
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TestStruct {
+    #[serde(with = "serde_duration::humantime")]
+    duration: Duration,
+}
+
+#[test]
+fn test_serialize_humantime() {
+    // Outputs of humantime::format_duration
+    let tests = vec![
+        (Duration::new(9420, 0), "2h 37m"),
+        (Duration::new(9425, 0), "2h 37m 5s"),
+        (Duration::new(0, 32_000_000), "32ms"),
+        (Duration::new(1, 1_001_001), "1s 1ms 1us 1ns"),
+        (Duration::ZERO, "0s"),
+        (Duration::from_secs(31_557_600), "1year"),
+        (Duration::from_secs(2 * 31_557_600), "2years"),
+        (Duration::from_secs(2_630_016), "1month"),
+        (Duration::from_secs(86400 * 3), "3days"),
+        (
+            Duration::from_secs(31_557_600 + 2 * 2_630_016 + 86400 + 3600),
+            "1year 2months 1day 1h",
+        ),
+    ];
+
+    for (duration, expected) in tests {
+        let serialized = serde_json::to_string(&TestStruct { duration }).unwrap();
+        assert_eq!(serialized, format!("{{\"duration\":\"{}\"}}", expected));
+    }
+}
+
+#[test]
+fn test_deserialize_humantime() {
+    // Inputs accepted by humantime::parse_duration
+    let tests = vec![
+        ("2h 37min", Duration::new(9420, 0)),
+        ("2h37m5s", Duration::new(9425, 0)),
+        ("32ms", Duration::new(0, 32_000_000)),
+        ("17nsec", Duration::new(0, 17)),
+        ("3usec 78us", Duration::new(0, 81_000)),
+        ("5hours 1hr", Duration::from_secs(6 * 3600)),
+        ("1M", Duration::from_secs(2_630_016)),
+        ("1m", Duration::from_secs(60)),
+        ("1y", Duration::from_secs(31_557_600)),
+        ("1year 2months 1day 1h", Duration::from_secs(36_907_632)),
+        ("  1 week  ", Duration::from_secs(604_800)),
+    ];
+
+    for (input, expected) in tests {
+        let json_str = format!("{{\"duration\":\"{}\"}}", input);
+        let deserialized: TestStruct = serde_json::from_str(&json_str)
+            .unwrap_or_else(|e| panic!("Failed to deserialize {}: {}", json_str, e));
+        assert_eq!(deserialized.duration, expected);
+    }
+}
+
+#[test]
+fn test_deserialize_humantime_malformed() {
+    for input in [
+        "",
+        "   ",
+        "30",
+        "1.5h",
+        "5x",
+        "1H",
+        "-1s",
+        "s",
+        "1s!",
+        "99999999999999999999s",
+    ] {
+        let json_str = format!("{{\"duration\":\"{}\"}}", input);
+        let result = serde_json::from_str::<TestStruct>(&json_str);
+        assert!(result.is_err(), "{} should not deserialize", input);
+    }
+}
+
+#[test]
+fn test_round_trip_humantime() {
+    for duration in [
+        Duration::new(9425, 1),
+        Duration::from_secs(100_000_000),
+        Duration::from_millis(1500),
+    ] {
+        let serialized = serde_json::to_string(&TestStruct { duration }).unwrap();
+        let deserialized: TestStruct = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.duration, duration);
+    }
+}
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TestStruct {
+    #[serde(with = "serde_duration::infinite")]
+    duration: Duration,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StrictStruct {
+    #[serde(with = "serde_duration")]
+    duration: Duration,
+}
+
+#[test]
+fn test_serialize_infinite() {
+    let tests = vec![
+        (Duration::MAX, "{\"duration\":\"inf\"}"),
+        (Duration::from_secs(90), "{\"duration\":\"90s\"}"),
+        (
+            Duration::new(u64::MAX, 0),
+            "{\"duration\":\"18446744073709551615s\"}",
+        ),
+    ];
+
+    for (duration, expected) in tests {
+        let serialized = serde_json::to_string(&TestStruct { duration }).unwrap();
+        assert_eq!(serialized, expected);
+    }
+}
+
+#[test]
+fn test_deserialize_infinite() {
+    let tests = vec![
+        ("{\"duration\":\"inf\"}", Duration::MAX),
+        ("{\"duration\":\"infinite\"}", Duration::MAX),
+        ("{\"duration\":\"INF\"}", Duration::MAX),
+        ("{\"duration\":\" Infinite \"}", Duration::MAX),
+        ("{\"duration\":\"30s\"}", Duration::from_secs(30)),
+        ("{\"duration\":30}", Duration::from_secs(30)),
+    ];
+
+    for (json_str, expected) in tests {
+        let deserialized: TestStruct = serde_json::from_str(json_str)
+            .unwrap_or_else(|e| panic!("Failed to deserialize {}: {}", json_str, e));
+        assert_eq!(deserialized.duration, expected);
+    }
+}
+
+#[test]
+fn test_round_trip_infinite() {
+    for duration in [Duration::MAX, Duration::ZERO, Duration::from_millis(1500)] {
+        let serialized = serde_json::to_string(&TestStruct { duration }).unwrap();
+        let deserialized: TestStruct = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.duration, duration);
+    }
+}
+
+#[test]
+fn test_infinite_is_opt_in() {
+    for input in ["inf", "infinite"] {
+        let json_str = format!("{{\"duration\":\"{}\"}}", input);
+        assert!(serde_json::from_str::<StrictStruct>(&json_str).is_err());
+    }
+    for input in ["infinity", "inf s", "-inf"] {
+        let json_str = format!("{{\"duration\":\"{}\"}}", input);
+        assert!(serde_json::from_str::<TestStruct>(&json_str).is_err());
+    }
+}
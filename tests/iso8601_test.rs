@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TestStruct {
+    #[serde(with = "serde_duration::iso8601")]
+    duration: Duration,
+}
+
+#[test]
+fn test_serialize_iso8601() {
+    let tests = vec![
+        (Duration::ZERO, "{\"duration\":\"PT0S\"}"),
+        (Duration::from_secs(30), "{\"duration\":\"PT30S\"}"),
+        (Duration::from_secs(5400), "{\"duration\":\"PT1H30M\"}"),
+        (Duration::from_secs(5415), "{\"duration\":\"PT1H30M15S\"}"),
+        (Duration::from_secs(26 * 3600), "{\"duration\":\"PT26H\"}"),
+        (Duration::from_millis(500), "{\"duration\":\"PT0.5S\"}"),
+        (
+            Duration::from_nanos(60_000_000_001),
+            "{\"duration\":\"PT1M0.000000001S\"}",
+        ),
+    ];
+
+    for (duration, expected) in tests {
+        let test_struct = TestStruct { duration };
+        let serialized = serde_json::to_string(&test_struct).unwrap();
+        assert_eq!(serialized, expected);
+    }
+}
+
+#[test]
+fn test_deserialize_iso8601() {
+    let tests = vec![
+        ("PT30S", Duration::from_secs(30)),
+        ("PT1H30M", Duration::from_secs(5400)),
+        ("P1DT2H", Duration::from_secs(26 * 3600)),
+        ("P1D", Duration::from_secs(86400)),
+        ("P2W", Duration::from_secs(14 * 86400)),
+        ("PT0.5S", Duration::from_millis(500)),
+        ("PT0,5S", Duration::from_millis(500)),
+        ("PT1.5H", Duration::from_secs(5400)),
+    ];
+
+    for (input, expected) in tests {
+        let json_str = format!("{{\"duration\":\"{}\"}}", input);
+        let deserialized: TestStruct = serde_json::from_str(&json_str)
+            .unwrap_or_else(|e| panic!("Failed to deserialize {}: {}", input, e));
+        assert_eq!(deserialized.duration, expected);
+    }
+}
+
+#[test]
+fn test_deserialize_iso8601_invalid() {
+    for input in [
+        "", "1h", "P", "PT", "P1DT", "P1M", "P1Y", "PT1S1M", "PT1H1H", "PTS", "PT1X", "PT1",
+    ] {
+        let json_str = format!("{{\"duration\":\"{}\"}}", input);
+        let result = serde_json::from_str::<TestStruct>(&json_str);
+        assert!(result.is_err(), "{} should not deserialize", input);
+    }
+}
+
+#[test]
+fn test_deserialize_iso8601_borrowed_and_owned() {
+    use serde::de::value::{BorrowedStrDeserializer, Error, StringDeserializer};
+
+    let borrowed = BorrowedStrDeserializer::<Error>::new("PT1H30M");
+    assert_eq!(
+        serde_duration::iso8601::deserialize(borrowed).unwrap(),
+        Duration::from_secs(5400)
+    );
+    let owned = StringDeserializer::<Error>::new("PT1H30M".to_string());
+    assert_eq!(
+        serde_duration::iso8601::deserialize(owned).unwrap(),
+        Duration::from_secs(5400)
+    );
+}
@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::time::Duration;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TestStruct {
+    #[serde(with = "serde_duration::map_values")]
+    timeouts: HashMap<String, Duration>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct OrderedStruct {
+    #[serde(with = "serde_duration::map_values")]
+    timeouts: BTreeMap<String, Duration>,
+}
+
+#[test]
+fn test_round_trip_map_values() {
+    let timeouts = HashMap::from([
+        ("connect".to_string(), Duration::from_secs(5)),
+        ("read".to_string(), Duration::from_secs(30)),
+    ]);
+    let serialized = serde_json::to_string(&TestStruct {
+        timeouts: timeouts.clone(),
+    })
+    .unwrap();
+    let deserialized: TestStruct = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized.timeouts, timeouts);
+}
+
+#[test]
+fn test_serialize_map_values() {
+    let timeouts = BTreeMap::from([
+        ("connect".to_string(), Duration::from_secs(5)),
+        ("read".to_string(), Duration::from_millis(1500)),
+    ]);
+    let serialized = serde_json::to_string(&OrderedStruct { timeouts }).unwrap();
+    assert_eq!(
+        serialized,
+        "{\"timeouts\":{\"connect\":\"5s\",\"read\":\"1500ms\"}}"
+    );
+}
+
+#[test]
+fn test_deserialize_map_values() {
+    let parsed: OrderedStruct =
+        serde_json::from_str("{\"timeouts\":{\"connect\":\"5s\",\"read\":30,\"idle\":\"1h\"}}")
+            .unwrap();
+    assert_eq!(
+        parsed.timeouts,
+        BTreeMap::from([
+            ("connect".to_string(), Duration::from_secs(5)),
+            ("idle".to_string(), Duration::from_secs(3600)),
+            ("read".to_string(), Duration::from_secs(30)),
+        ])
+    );
+    let parsed: OrderedStruct = serde_json::from_str("{\"timeouts\":{}}").unwrap();
+    assert!(parsed.timeouts.is_empty());
+}
+
+#[test]
+fn test_deserialize_map_values_invalid_value() {
+    let error =
+        serde_json::from_str::<TestStruct>("{\"timeouts\":{\"connect\":\"5s\",\"read\":\"30x\"}}")
+            .unwrap_err();
+    assert!(error.to_string().contains("at key \"read\""), "{}", error);
+    assert!(error.to_string().contains("unknown unit 'x'"), "{}", error);
+}
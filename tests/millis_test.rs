@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TestStruct {
+    #[serde(with = "serde_duration::millis")]
+    duration: Duration,
+}
+
+#[test]
+fn test_serialize_millis() {
+    let tests = vec![
+        (Duration::from_millis(1500), "{\"duration\":\"1500ms\"}"),
+        (Duration::from_millis(250), "{\"duration\":\"250ms\"}"),
+        (Duration::from_secs(60), "{\"duration\":\"60000ms\"}"),
+        (Duration::ZERO, "{\"duration\":\"0s\"}"),
+        (Duration::from_micros(250), "{\"duration\":\"0.25ms\"}"),
+        (
+            Duration::from_nanos(1_000_001),
+            "{\"duration\":\"1.000001ms\"}",
+        ),
+    ];
+
+    for (duration, expected) in tests {
+        let serialized = serde_json::to_string(&TestStruct { duration }).unwrap();
+        assert_eq!(serialized, expected);
+    }
+}
+
+#[test]
+fn test_deserialize_millis() {
+    let tests = vec![
+        ("{\"duration\":\"1500ms\"}", Duration::from_millis(1500)),
+        ("{\"duration\":\"250ms\"}", Duration::from_millis(250)),
+        ("{\"duration\":\"1.5s\"}", Duration::from_millis(1500)),
+        ("{\"duration\":\"0.25ms\"}", Duration::from_micros(250)),
+    ];
+
+    for (json_str, expected) in tests {
+        let deserialized: TestStruct = serde_json::from_str(json_str)
+            .unwrap_or_else(|e| panic!("Failed to deserialize {}: {}", json_str, e));
+        assert_eq!(deserialized.duration, expected);
+    }
+}
+
+#[test]
+fn test_round_trip_millis() {
+    for duration in [
+        Duration::from_millis(1500),
+        Duration::from_millis(250),
+        Duration::new(5400, 1),
+    ] {
+        let serialized = serde_json::to_string(&TestStruct { duration }).unwrap();
+        let deserialized: TestStruct = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.duration, duration);
+    }
+}
@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TestStruct {
+    #[serde(default, with = "serde_duration::null_as_zero")]
+    duration: Duration,
+}
+
+#[test]
+fn test_deserialize_null_as_zero() {
+    let tests = vec![
+        ("{\"duration\":null}", Duration::ZERO),
+        ("{\"duration\":\"30s\"}", Duration::from_secs(30)),
+        ("{\"duration\":\"1h30m\"}", Duration::from_secs(5400)),
+        ("{\"duration\":90}", Duration::from_secs(90)),
+        ("{}", Duration::ZERO),
+    ];
+
+    for (input, expected) in tests {
+        let parsed: TestStruct = serde_json::from_str(input)
+            .unwrap_or_else(|e| panic!("Failed to parse {}: {}", input, e));
+        assert_eq!(parsed.duration, expected);
+    }
+}
+
+#[test]
+fn test_serialize_null_as_zero() {
+    let tests = vec![
+        (Duration::ZERO, "{\"duration\":\"0s\"}"),
+        (Duration::from_secs(30), "{\"duration\":\"30s\"}"),
+    ];
+
+    for (duration, expected) in tests {
+        let serialized = serde_json::to_string(&TestStruct { duration }).unwrap();
+        assert_eq!(serialized, expected);
+    }
+}
+
+#[test]
+fn test_deserialize_null_as_zero_invalid() {
+    let tests = vec![
+        ("{\"duration\":\"5x\"}", "unknown unit 'x'"),
+        ("{\"duration\":\"\"}", "empty duration"),
+    ];
+
+    for (input, expected) in tests {
+        let error = serde_json::from_str::<TestStruct>(input).unwrap_err();
+        assert!(
+            error.to_string().contains(expected),
+            "{:?} does not contain {:?}",
+            error.to_string(),
+            expected
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TestStruct {
+    #[serde(with = "serde_duration::object")]
+    duration: Duration,
+}
+
+#[test]
+fn test_serialize_object() {
+    let tests = vec![
+        (Duration::from_secs(5400), "{\"hours\":1,\"minutes\":30}"),
+        (
+            Duration::from_secs(90_061),
+            "{\"days\":1,\"hours\":1,\"minutes\":1,\"seconds\":1}",
+        ),
+        (
+            Duration::from_millis(1500),
+            "{\"seconds\":1,\"millis\":500}",
+        ),
+        (Duration::new(0, 1_001), "{\"micros\":1,\"nanos\":1}"),
+        (Duration::ZERO, "{}"),
+    ];
+
+    for (duration, expected) in tests {
+        let serialized = serde_json::to_string(&TestStruct { duration }).unwrap();
+        assert_eq!(serialized, format!("{{\"duration\":{}}}", expected));
+    }
+}
+
+#[test]
+fn test_deserialize_object() {
+    let tests = vec![
+        ("{\"hours\":1,\"minutes\":30}", Duration::from_secs(5400)),
+        ("{\"minutes\":90}", Duration::from_secs(5400)),
+        ("{\"days\":2}", Duration::from_secs(172_800)),
+        (
+            "{\"seconds\":1,\"millis\":1500}",
+            Duration::from_millis(2500),
+        ),
+        ("{}", Duration::ZERO),
+    ];
+
+    for (object, expected) in tests {
+        let json_str = format!("{{\"duration\":{}}}", object);
+        let deserialized: TestStruct = serde_json::from_str(&json_str)
+            .unwrap_or_else(|e| panic!("Failed to deserialize {}: {}", json_str, e));
+        assert_eq!(deserialized.duration, expected);
+    }
+}
+
+#[test]
+fn test_deserialize_object_invalid() {
+    let tests = vec![
+        ("{\"foo\":1}", "unknown field `foo`"),
+        ("{\"hours\":1,\"hours\":2}", "duplicate field `hours`"),
+        ("{\"hours\":-1}", "invalid value"),
+        ("{\"hours\":1.5}", "invalid type"),
+        ("\"1h\"", "invalid type"),
+        ("{\"days\":18446744073709551615}", "duration is too large"),
+    ];
+
+    for (object, expected) in tests {
+        let json_str = format!("{{\"duration\":{}}}", object);
+        let error = serde_json::from_str::<TestStruct>(&json_str).unwrap_err();
+        assert!(
+            error.to_string().contains(expected),
+            "{:?} does not contain {:?}",
+            error.to_string(),
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_round_trip_object() {
+    for duration in [
+        Duration::MAX,
+        Duration::new(5400, 1),
+        Duration::from_secs(59),
+    ] {
+        let serialized = serde_json::to_string(&TestStruct { duration }).unwrap();
+        let deserialized: TestStruct = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.duration, duration);
+    }
+}
@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TestStruct {
+    #[serde(default, with = "serde_duration::option")]
+    duration: Option<Duration>,
+}
+
+#[test]
+fn test_serialize_option() {
+    let tests = vec![
+        (Some(Duration::from_secs(30)), "{\"duration\":\"30s\"}"),
+        (None, "{\"duration\":null}"),
+    ];
+
+    for (duration, expected) in tests {
+        let test_struct = TestStruct { duration };
+        let serialized = serde_json::to_string(&test_struct).unwrap();
+        assert_eq!(serialized, expected);
+    }
+}
+
+#[test]
+fn test_deserialize_option() {
+    let tests = vec![
+        ("{\"duration\":\"30s\"}", Some(Duration::from_secs(30))),
+        ("{\"duration\":null}", None),
+        ("{}", None),
+    ];
+
+    for (json_str, expected) in tests {
+        let deserialized: TestStruct = serde_json::from_str(json_str)
+            .unwrap_or_else(|e| panic!("Failed to deserialize JSON: {}", e));
+        assert_eq!(deserialized.duration, expected);
+    }
+}
+
+#[test]
+fn test_deserialize_option_invalid() {
+    let result = serde_json::from_str::<TestStruct>("{\"duration\":\"5x\"}");
+    assert!(result.is_err());
+}
@@ -0,0 +1,80 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct TestStruct {
+    #[serde(with = "serde_duration::pair")]
+    duration: Duration,
+}
+
+#[test]
+fn test_pair_deserialize() {
+    let tests = vec![
+        (r#"[30,"s"]"#, Duration::from_secs(30)),
+        (r#"[90,"m"]"#, Duration::from_secs(5400)),
+        (r#"[1.5,"h"]"#, Duration::from_secs(5400)),
+        (r#"[0.1,"s"]"#, Duration::from_millis(100)),
+        (r#"[250,"MS"]"#, Duration::from_millis(250)),
+        (r#"[2,"minutes"]"#, Duration::from_secs(120)),
+        (r#"[0,"s"]"#, Duration::ZERO),
+    ];
+
+    for (input, expected) in tests {
+        let json = format!("{{\"duration\":{}}}", input);
+        let parsed: TestStruct = serde_json::from_str(&json)
+            .unwrap_or_else(|e| panic!("Failed to parse {}: {}", json, e));
+        assert_eq!(parsed.duration, expected, "{}", input);
+    }
+}
+
+#[test]
+fn test_pair_deserialize_invalid() {
+    let tests = vec![
+        (r#"[30,"x"]"#, "unknown unit 'x'"),
+        (r#"[30,""]"#, "unknown unit ''"),
+        (r#"[-30,"s"]"#, "invalid value: integer `-30`"),
+        (r#"["30","s"]"#, "invalid type: string \"30\""),
+        (r#"[30]"#, "invalid length 1"),
+        (r#"[]"#, "invalid length 0"),
+        (r#"[1e40,"w"]"#, "duration is too large"),
+        (r#""30s""#, "invalid type: string"),
+    ];
+
+    for (input, expected) in tests {
+        let json = format!("{{\"duration\":{}}}", input);
+        let error = serde_json::from_str::<TestStruct>(&json).unwrap_err();
+        assert!(
+            error.to_string().contains(expected),
+            "{:?} does not contain {:?}",
+            error.to_string(),
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_pair_serialize() {
+    let tests = vec![
+        (Duration::from_secs(30), r#"[30,"s"]"#),
+        (Duration::from_secs(5400), r#"[90,"m"]"#),
+        (Duration::from_millis(1500), r#"[1500,"ms"]"#),
+        (Duration::from_secs(604_800), r#"[1,"w"]"#),
+        (Duration::ZERO, r#"[0,"s"]"#),
+    ];
+
+    for (duration, expected) in tests {
+        let value = TestStruct { duration };
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, format!("{{\"duration\":{}}}", expected));
+        assert_eq!(serde_json::from_str::<TestStruct>(&json).unwrap(), value);
+    }
+
+    // Too many nanoseconds for a u64
+    let value = TestStruct {
+        duration: Duration::MAX,
+    };
+    assert_eq!(
+        serde_json::to_string(&value).unwrap(),
+        r#"{"duration":[18446744073709551615999999999,"ns"]}"#
+    );
+}
@@ -0,0 +1,1061 @@
+use serde_duration::{
+    parse_duration, parse_duration_checked, parse_duration_in, parse_duration_saturating,
+    parse_duration_with_default, parse_duration_with_mode, parse_duration_with_options,
+    try_parse_duration, DurationParseError, DurationTokens, InvalidDurationError, ParseMode,
+    ParseOptions, Unit,
+};
+use std::time::Duration;
+
+#[test]
+fn test_parse_duration() {
+    let tests = vec![
+        ("30s", Duration::from_secs(30)),
+        ("5m", Duration::from_secs(300)),
+        ("1h30m", Duration::from_secs(5400)),
+        ("1.5s", Duration::from_millis(1500)),
+        ("250us", Duration::from_micros(250)),
+    ];
+
+    for (input, expected) in tests {
+        let parsed = parse_duration(input)
+            .unwrap_or_else(|e| panic!("Failed to parse {}: {}", input, e));
+        assert_eq!(parsed, expected);
+    }
+}
+
+#[test]
+fn test_parse_duration_invalid() {
+    for input in ["", "30", "5x", "s", "1h30", "abc"] {
+        assert!(parse_duration(input).is_err(), "{} should not parse", input);
+    }
+}
+
+#[test]
+fn test_invalid_duration_error_is_public() {
+    let error: InvalidDurationError = parse_duration("5x").unwrap_err();
+    let boxed: Box<dyn std::error::Error> = Box::new(error);
+    assert!(!boxed.to_string().is_empty());
+}
+
+#[test]
+fn test_parse_duration_with_default() {
+    let tests = vec![
+        ("30", Unit::Seconds, Duration::from_secs(30)),
+        (" 30 ", Unit::Seconds, Duration::from_secs(30)),
+        ("30", Unit::Millis, Duration::from_millis(30)),
+        ("1.5", Unit::Minutes, Duration::from_secs(90)),
+        ("30m", Unit::Seconds, Duration::from_secs(1800)),
+    ];
+
+    for (input, default_unit, expected) in tests {
+        let parsed = parse_duration_with_default(input, default_unit)
+            .unwrap_or_else(|e| panic!("Failed to parse {}: {}", input, e));
+        assert_eq!(parsed, expected);
+    }
+}
+
+#[test]
+fn test_parse_duration_without_default() {
+    assert!(parse_duration("30").is_err());
+    assert!(parse_duration_with_default("30x", Unit::Seconds).is_err());
+}
+
+#[test]
+fn test_parse_duration_overflow() {
+    for input in [
+        "100000000000000000h",
+        "30000000000000000w",
+        "1s18446744073709551615s",
+    ] {
+        let error = parse_duration(input).unwrap_err();
+        assert!(
+            error.to_string().contains("too large"),
+            "{} gave {}",
+            input,
+            error
+        );
+    }
+}
+
+#[test]
+fn test_parse_duration_multi_byte_characters() {
+    for input in ["5€", "€", "5€s", "1h5€", "5µ", "µs", "5s€", "５s"] {
+        assert!(parse_duration(input).is_err(), "{} should not parse", input);
+    }
+    assert_eq!(parse_duration("5µs").unwrap(), Duration::from_micros(5));
+}
+
+#[test]
+fn test_parse_duration_empty() {
+    for input in ["", "   "] {
+        let error = parse_duration(input).unwrap_err();
+        assert_eq!(error.to_string(), "empty duration");
+    }
+    let error = parse_duration_with_default("", Unit::Seconds).unwrap_err();
+    assert_eq!(error.to_string(), "empty duration");
+}
+
+#[test]
+fn test_parse_duration_unit_only() {
+    let tests = vec![
+        (
+            "s",
+            "invalid duration \"s\": missing numeric value before unit 's'",
+        ),
+        (
+            "ms",
+            "invalid duration \"ms\": missing numeric value before unit 'ms'",
+        ),
+        (
+            "1h m",
+            "invalid duration \"1h m\": missing numeric value before unit 'm' at position 3",
+        ),
+    ];
+
+    for (input, expected) in tests {
+        assert_eq!(parse_duration(input).unwrap_err().to_string(), expected);
+    }
+}
+
+#[test]
+fn test_parse_duration_full_words() {
+    let tests = vec![
+        ("10 seconds", Duration::from_secs(10)),
+        ("1 second", Duration::from_secs(1)),
+        ("1 minute", Duration::from_secs(60)),
+        ("5 minutes", Duration::from_secs(300)),
+        ("1 hour", Duration::from_secs(3600)),
+        ("2 hours", Duration::from_secs(7200)),
+        ("1 day", Duration::from_secs(86400)),
+        ("2 weeks", Duration::from_secs(14 * 86400)),
+        ("250 milliseconds", Duration::from_millis(250)),
+        ("1 microsecond", Duration::from_micros(1)),
+        ("3 nanoseconds", Duration::from_nanos(3)),
+        ("1.5 Hours", Duration::from_secs(5400)),
+        ("30sec", Duration::from_secs(30)),
+        ("5 min", Duration::from_secs(300)),
+        ("2hr", Duration::from_secs(7200)),
+    ];
+
+    for (input, expected) in tests {
+        let parsed =
+            parse_duration(input).unwrap_or_else(|e| panic!("Failed to parse {}: {}", input, e));
+        assert_eq!(parsed, expected);
+    }
+}
+
+#[test]
+fn test_parse_duration_mixed_compound() {
+    let tests = vec![
+        ("1 hour 30 minutes", Duration::from_secs(5400)),
+        ("1hour30minutes", Duration::from_secs(5400)),
+        ("1 hour 30m", Duration::from_secs(5400)),
+        ("1h 30 minutes 15s", Duration::from_secs(5415)),
+        ("2 days 3 hours", Duration::from_secs(2 * 86400 + 3 * 3600)),
+        ("1 minute 1m", Duration::from_secs(120)),
+        ("1 second 500 ms", Duration::from_millis(1500)),
+    ];
+
+    for (input, expected) in tests {
+        let parsed =
+            parse_duration(input).unwrap_or_else(|e| panic!("Failed to parse {}: {}", input, e));
+        assert_eq!(parsed, expected);
+    }
+}
+
+#[test]
+fn test_parse_duration_beyond_u64_values() {
+    let tests = vec![
+        (
+            "20000000000000000000ns",
+            Duration::from_secs(20_000_000_000),
+        ),
+        (
+            "18446744073709551616us",
+            Duration::new(18_446_744_073_709, 551_616_000),
+        ),
+        (
+            "1000000000000000000000ms",
+            Duration::from_secs(1_000_000_000_000_000_000),
+        ),
+        (
+            "20000000000000000000.5ns",
+            Duration::from_secs(20_000_000_000),
+        ),
+    ];
+
+    for (input, expected) in tests {
+        let parsed =
+            parse_duration(input).unwrap_or_else(|e| panic!("Failed to parse {}: {}", input, e));
+        assert_eq!(parsed, expected);
+    }
+
+    for input in [
+        "18446744073709551616s",
+        "1000000000000000000000000000000000000000ns",
+    ] {
+        let error = parse_duration(input).unwrap_err();
+        assert!(
+            error.to_string().contains("too large"),
+            "{} gave {}",
+            input,
+            error
+        );
+    }
+}
+
+#[test]
+fn test_parse_duration_negative() {
+    let error = parse_duration("-30s").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid duration \"-30s\": negative durations are not supported for std::time::Duration"
+    );
+    assert!(parse_duration_with_default("-30", Unit::Seconds).is_err());
+}
+
+#[test]
+fn test_parse_duration_digit_separators() {
+    let tests = vec![
+        ("1_000s", Duration::from_secs(1000)),
+        ("1_000_000ms", Duration::from_secs(1000)),
+        ("10_000ms", Duration::from_secs(10)),
+        ("1_0.5_0s", Duration::from_millis(10_500)),
+        ("1h 1_0m", Duration::from_secs(4200)),
+    ];
+
+    for (input, expected) in tests {
+        let parsed =
+            parse_duration(input).unwrap_or_else(|e| panic!("Failed to parse {}: {}", input, e));
+        assert_eq!(parsed, expected);
+    }
+    assert_eq!(
+        parse_duration_with_default("1_000", Unit::Millis).unwrap(),
+        Duration::from_secs(1)
+    );
+}
+
+#[test]
+fn test_parse_duration_misplaced_separators() {
+    let error = parse_duration("_1s").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid duration \"_1s\": misplaced digit separator in \"_1\", '_' must be between digits"
+    );
+    for input in ["1_s", "1__000s", "1_.5s", "1._5s", "1h _30m"] {
+        let error = parse_duration(input).unwrap_err();
+        assert!(
+            error.to_string().contains("misplaced digit separator"),
+            "{} gave {}",
+            input,
+            error
+        );
+    }
+}
+
+#[test]
+fn test_parse_duration_plus_sign() {
+    let tests = vec![
+        ("+30s", Duration::from_secs(30)),
+        (" +1h30m", Duration::from_secs(5400)),
+        ("+1.5 ms", Duration::from_micros(1500)),
+    ];
+
+    for (input, expected) in tests {
+        let parsed =
+            parse_duration(input).unwrap_or_else(|e| panic!("Failed to parse {}: {}", input, e));
+        assert_eq!(parsed, expected);
+    }
+    assert_eq!(
+        parse_duration_with_default("+30", Unit::Seconds).unwrap(),
+        Duration::from_secs(30)
+    );
+
+    for input in ["+", "++30s", "+-30s", "1h+30m"] {
+        assert!(parse_duration(input).is_err(), "{} should not parse", input);
+    }
+    assert_eq!(
+        parse_duration("+").unwrap_err().to_string(),
+        "invalid duration \"+\": missing duration after '+'"
+    );
+}
+
+#[test]
+fn test_parse_duration_months_and_years() {
+    let tests = vec![
+        ("1mo", Duration::from_secs(2_592_000)),
+        ("1y", Duration::from_secs(31_536_000)),
+        ("2 months", Duration::from_secs(5_184_000)),
+        ("1 year", Duration::from_secs(31_536_000)),
+        ("1yr 1mo", Duration::from_secs(34_128_000)),
+        ("0.5y", Duration::from_secs(15_768_000)),
+        ("1MO", Duration::from_secs(2_592_000)),
+        // "M" on its own is still minutes
+        ("1M", Duration::from_secs(60)),
+    ];
+
+    for (input, expected) in tests {
+        let parsed =
+            parse_duration(input).unwrap_or_else(|e| panic!("Failed to parse {}: {}", input, e));
+        assert_eq!(parsed, expected);
+    }
+}
+
+#[test]
+fn test_parse_duration_with_options() {
+    let thirty_days = ParseOptions::new();
+    let average = ParseOptions::new().secs_per_month(2_629_746);
+    let tests = vec![
+        ("1mo", thirty_days, Duration::from_secs(2_592_000)),
+        ("1mo", average, Duration::from_secs(2_629_746)),
+        ("0.5mo", average, Duration::from_secs(1_314_873)),
+        ("2 months 1d", average, Duration::from_secs(5_345_892)),
+        (
+            "1y",
+            ParseOptions::new().secs_per_year(31_556_952),
+            Duration::from_secs(31_556_952),
+        ),
+        ("1h30m", average, Duration::from_secs(5400)),
+    ];
+
+    for (input, options, expected) in tests {
+        let parsed = parse_duration_with_options(input, &options)
+            .unwrap_or_else(|e| panic!("Failed to parse {}: {}", input, e));
+        assert_eq!(parsed, expected);
+    }
+
+    assert_eq!(ParseOptions::default(), ParseOptions::new());
+    assert!(parse_duration_with_options("30", &average).is_err());
+    let huge = ParseOptions::new().secs_per_year(u64::MAX);
+    assert!(parse_duration_with_options("2y", &huge).is_err());
+    assert!(parse_duration_with_options("0.5y", &huge).is_ok());
+}
+
+#[test]
+fn test_parse_duration_error_kinds() {
+    let kind = |input: &str| {
+        let error = parse_duration(input).unwrap_err();
+        assert_eq!(error.input(), input);
+        error.kind().to_string()
+    };
+
+    assert!(matches!(
+        parse_duration("  ").unwrap_err().kind(),
+        DurationParseError::EmptyInput
+    ));
+    assert!(matches!(
+        parse_duration("1h s").unwrap_err().kind(),
+        DurationParseError::MissingValue(unit) if unit == "s"
+    ));
+    assert!(matches!(
+        parse_duration("30").unwrap_err().kind(),
+        DurationParseError::MissingUnit
+    ));
+    assert!(matches!(
+        parse_duration("5x").unwrap_err().kind(),
+        DurationParseError::UnknownUnit(unit) if unit == "x"
+    ));
+    assert!(matches!(
+        parse_duration("1.2.3s").unwrap_err().kind(),
+        DurationParseError::InvalidNumber(number) if number == "1.2.3"
+    ));
+    assert!(matches!(
+        parse_duration("30000000000000000w").unwrap_err().kind(),
+        DurationParseError::Overflow
+    ));
+    assert!(matches!(
+        parse_duration("-1s").unwrap_err().kind(),
+        DurationParseError::Negative
+    ));
+    assert!(matches!(
+        parse_duration("1__0s").unwrap_err().kind(),
+        DurationParseError::MisplacedSeparator(number) if number == "1__0"
+    ));
+    assert!(matches!(
+        parse_duration("+").unwrap_err().kind(),
+        DurationParseError::InvalidFormat(_)
+    ));
+
+    assert_eq!(kind("5x"), "unknown unit 'x'");
+    assert_eq!(kind("30000000000000000w"), "duration is too large");
+}
+
+#[test]
+fn test_parse_duration_errors_compare_equal() {
+    let error = parse_duration("5x").unwrap_err();
+    assert_eq!(error, parse_duration("5x").unwrap_err());
+    assert_eq!(error.clone(), error);
+    assert_ne!(error, parse_duration("6x").unwrap_err());
+    assert_eq!(
+        error.kind(),
+        &DurationParseError::UnknownUnit("x".to_string())
+    );
+    assert_eq!(
+        parse_duration("").unwrap_err().kind(),
+        &DurationParseError::EmptyInput
+    );
+}
+
+#[test]
+fn test_parse_duration_error_position() {
+    let tests = vec![
+        ("1h30x15s", 4),
+        ("  1h 30x", 7),
+        ("1h30", 4),
+        ("1h30m 1.2.3s", 6),
+        ("1h1_s", 2),
+        ("5x", 1),
+        ("+5x", 2),
+        ("_1s", 0),
+    ];
+
+    for (input, expected) in tests {
+        let error = parse_duration(input).unwrap_err();
+        assert_eq!(error.position(), Some(expected), "{} gave {}", input, error);
+    }
+
+    let error = parse_duration("1h30x15s").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid duration \"1h30x15s\": unknown unit 'x' at position 4"
+    );
+    let error = parse_duration_with_default(" 1.2.3", Unit::Seconds).unwrap_err();
+    assert_eq!(error.position(), Some(1));
+}
+
+#[test]
+fn test_parse_duration_aliases() {
+    let tests = vec![
+        ("nsec", Duration::from_nanos(2)),
+        ("nsecs", Duration::from_nanos(2)),
+        ("nanos", Duration::from_nanos(2)),
+        ("usec", Duration::from_micros(2)),
+        ("usecs", Duration::from_micros(2)),
+        ("micros", Duration::from_micros(2)),
+        ("msec", Duration::from_millis(2)),
+        ("msecs", Duration::from_millis(2)),
+        ("millis", Duration::from_millis(2)),
+        ("sec", Duration::from_secs(2)),
+        ("secs", Duration::from_secs(2)),
+        ("min", Duration::from_secs(120)),
+        ("mins", Duration::from_secs(120)),
+        ("hr", Duration::from_secs(7200)),
+        ("hrs", Duration::from_secs(7200)),
+        ("wk", Duration::from_secs(1_209_600)),
+        ("wks", Duration::from_secs(1_209_600)),
+        ("mos", Duration::from_secs(5_184_000)),
+        ("yr", Duration::from_secs(63_072_000)),
+        ("yrs", Duration::from_secs(63_072_000)),
+    ];
+
+    for (alias, expected) in tests {
+        for input in [format!("2{}", alias), format!("2 {}", alias.to_uppercase())] {
+            let parsed = parse_duration(&input)
+                .unwrap_or_else(|e| panic!("Failed to parse {}: {}", input, e));
+            assert_eq!(parsed, expected);
+        }
+    }
+    assert_eq!(
+        parse_duration("1 hr 30 mins 15 secs").unwrap(),
+        Duration::from_secs(5415)
+    );
+}
+
+#[test]
+fn test_parse_duration_too_long() {
+    let digits = "1".repeat(1_000_000) + "s";
+    let error = parse_duration(&digits).unwrap_err();
+    assert_eq!(error.kind(), &DurationParseError::TooLong(256));
+    assert!(error.input().len() < 64);
+    assert!(error
+        .to_string()
+        .contains("duration is longer than the maximum of 256 bytes"));
+
+    let error = parse_duration_with_default(&digits, Unit::Seconds).unwrap_err();
+    assert_eq!(error.kind(), &DurationParseError::TooLong(256));
+
+    let compound = "1h 2m 3s ".repeat(20);
+    assert_eq!(
+        parse_duration(&compound).unwrap(),
+        Duration::from_secs(20 * 3723)
+    );
+
+    let options = ParseOptions::new().max_len(4);
+    assert_eq!(
+        parse_duration_with_options("1h2m", &options).unwrap(),
+        Duration::from_secs(3720)
+    );
+    let error = parse_duration_with_options("1h2m3s", &options).unwrap_err();
+    assert_eq!(error.kind(), &DurationParseError::TooLong(4));
+    // Short inputs are kept whole, while long ones are cut with "..."
+    let error = parse_duration_with_options("123456s", &options).unwrap_err();
+    assert_eq!(error.input(), "123456s");
+    let error = parse_duration(&digits).unwrap_err();
+    assert_eq!(error.input(), format!("{}...", "1".repeat(32)));
+}
+
+#[test]
+fn test_parse_duration_strict() {
+    let strict = ParseOptions::new().strict(true);
+    let tests = vec![
+        ("1h30m", Duration::from_secs(5400)),
+        ("1d 2h 3m 4s 5ms", Duration::from_millis(93_784_005)),
+        ("1y1mo1w", Duration::from_secs(34_732_800)),
+        ("90m", Duration::from_secs(5400)),
+    ];
+    for (input, expected) in tests {
+        let parsed = parse_duration_with_options(input, &strict)
+            .unwrap_or_else(|e| panic!("Failed to parse {}: {}", input, e));
+        assert_eq!(parsed, expected);
+    }
+
+    let errors = vec![
+        (
+            "30s1h",
+            DurationParseError::MisorderedUnit("h".to_string()),
+            4,
+        ),
+        (
+            "1h 2s 3m",
+            DurationParseError::MisorderedUnit("m".to_string()),
+            7,
+        ),
+        (
+            "1h1h",
+            DurationParseError::DuplicateUnit("h".to_string()),
+            3,
+        ),
+        (
+            "1 hour 2 hrs",
+            DurationParseError::DuplicateUnit("hrs".to_string()),
+            9,
+        ),
+    ];
+    for (input, expected, position) in errors {
+        let error = parse_duration_with_options(input, &strict).unwrap_err();
+        assert_eq!(error.kind(), &expected, "{}", input);
+        assert_eq!(error.position(), Some(position), "{}", input);
+        // Without strict mode the parts are added up.
+        assert!(parse_duration(input).is_ok(), "{}", input);
+    }
+
+    let error = parse_duration_with_options("30s1h", &strict).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("unit 'h' must come before smaller units"));
+    let error = parse_duration_with_options("1h1h", &strict).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("unit 'h' appears more than once"));
+}
+
+#[test]
+fn test_parse_duration_saturating() {
+    let tests = vec![
+        ("18446744073709551616s", Duration::MAX),
+        ("99999999999999999999999999999999999999999w", Duration::MAX),
+        ("18446744073709551615s 1s", Duration::MAX),
+        ("1h 18446744073709551616s 30m", Duration::MAX),
+        ("18446744073709551615s", Duration::from_secs(u64::MAX)),
+        ("1h30m", Duration::from_secs(5400)),
+    ];
+    for (input, expected) in tests {
+        let parsed = parse_duration_saturating(input)
+            .unwrap_or_else(|e| panic!("Failed to parse {}: {}", input, e));
+        assert_eq!(parsed, expected, "{}", input);
+    }
+
+    assert_eq!(
+        parse_duration("18446744073709551616s").unwrap_err().kind(),
+        &DurationParseError::Overflow
+    );
+    for (input, expected) in [
+        ("", DurationParseError::EmptyInput),
+        ("-5s", DurationParseError::Negative),
+        (
+            "18446744073709551616s 5x",
+            DurationParseError::UnknownUnit("x".to_string()),
+        ),
+    ] {
+        assert_eq!(
+            parse_duration_saturating(input).unwrap_err().kind(),
+            &expected
+        );
+    }
+}
+
+#[test]
+fn test_parse_duration_decimal_comma() {
+    let options = ParseOptions::new().decimal_comma(true);
+    let tests = vec![
+        ("1,5h", Duration::from_secs(5400)),
+        ("0,25s", Duration::from_millis(250)),
+        ("1h 2,5m", Duration::from_secs(3750)),
+        ("1.5h", Duration::from_secs(5400)),
+        ("1_000,5ms", Duration::from_micros(1_000_500)),
+    ];
+    for (input, expected) in tests {
+        let parsed = parse_duration_with_options(input, &options)
+            .unwrap_or_else(|e| panic!("Failed to parse {}: {}", input, e));
+        assert_eq!(parsed, expected);
+    }
+
+    for input in ["1,5,5h", "1,5.5h", ",5h"] {
+        let error = parse_duration_with_options(input, &options).unwrap_err();
+        assert_eq!(
+            error.kind(),
+            &DurationParseError::InvalidNumber(input.trim_end_matches('h').to_string())
+        );
+    }
+
+    // The dot stays the only decimal separator by default.
+    let error = parse_duration("1,5h").unwrap_err();
+    assert_eq!(
+        error.kind(),
+        &DurationParseError::InvalidNumber("1,5".to_string())
+    );
+}
+
+#[test]
+fn test_parse_duration_scientific() {
+    let options = ParseOptions::new().scientific(true);
+    let tests = vec![
+        ("1e3s", Duration::from_secs(1000)),
+        ("1.5e2ms", Duration::from_millis(150)),
+        ("1E3s", Duration::from_secs(1000)),
+        ("1e+3s", Duration::from_secs(1000)),
+        ("2.5e-1s", Duration::from_millis(250)),
+        ("1.5e-3h", Duration::from_millis(5400)),
+        ("123e-2s", Duration::from_millis(1230)),
+        ("0.001e3s", Duration::from_secs(1)),
+        ("1e-10s", Duration::ZERO),
+        ("0e999999999999s", Duration::ZERO),
+        ("1e-999999999999s", Duration::ZERO),
+        ("1e1h 1e1m", Duration::from_secs(36_600)),
+        ("30s", Duration::from_secs(30)),
+    ];
+    for (input, expected) in tests {
+        let parsed = parse_duration_with_options(input, &options)
+            .unwrap_or_else(|e| panic!("Failed to parse {}: {}", input, e));
+        assert_eq!(parsed, expected, "{}", input);
+    }
+
+    let errors = vec![
+        ("1e20s", DurationParseError::Overflow),
+        ("1e999999999999s", DurationParseError::Overflow),
+        ("1.e3s", DurationParseError::UnknownUnit("e".to_string())),
+        (
+            "1e3.5s",
+            DurationParseError::InvalidNumber("1e3.5".to_string()),
+        ),
+        (
+            "1e3e3s",
+            DurationParseError::InvalidNumber("1e3e3".to_string()),
+        ),
+    ];
+    for (input, expected) in errors {
+        let error = parse_duration_with_options(input, &options).unwrap_err();
+        assert_eq!(error.kind(), &expected, "{}", input);
+    }
+
+    // Without the option an exponent is an invalid number.
+    assert_eq!(
+        parse_duration("1e3s").unwrap_err().kind(),
+        &DurationParseError::InvalidNumber("1e3".to_string())
+    );
+}
+
+#[test]
+fn test_parse_duration_zero_components() {
+    let tests = vec![
+        ("1h0m0s", Duration::from_secs(3600)),
+        ("0h30m0s", Duration::from_secs(1800)),
+        ("1h0m0.5s", Duration::from_millis(3_600_500)),
+        ("0h0m0s", Duration::ZERO),
+        ("1h 0m 0s 0ms", Duration::from_secs(3600)),
+    ];
+    for (input, expected) in tests {
+        let parsed =
+            parse_duration(input).unwrap_or_else(|e| panic!("Failed to parse {}: {}", input, e));
+        assert_eq!(parsed, expected);
+        let strict = ParseOptions::new().strict(true);
+        assert_eq!(
+            parse_duration_with_options(input, &strict).unwrap(),
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_try_parse_duration() {
+    let durations = vec![
+        ("30s", Duration::from_secs(30)),
+        (" 1h30m ", Duration::from_secs(5400)),
+    ];
+    for (input, expected) in durations {
+        assert_eq!(try_parse_duration(input).unwrap(), Some(expected));
+    }
+
+    for input in ["hello", "30", "5x", "/tmp/file", "true"] {
+        assert_eq!(
+            try_parse_duration(input).unwrap(),
+            None,
+            "{} should not be a duration",
+            input
+        );
+    }
+
+    let errors = vec![
+        ("", DurationParseError::EmptyInput),
+        ("1h 5x", DurationParseError::UnknownUnit("x".to_string())),
+        ("1h30", DurationParseError::MissingUnit),
+        (
+            "1.2.3s",
+            DurationParseError::InvalidNumber("1.2.3".to_string()),
+        ),
+        ("-5s", DurationParseError::Negative),
+    ];
+    for (input, expected) in errors {
+        assert_eq!(try_parse_duration(input).unwrap_err().kind(), &expected);
+    }
+}
+
+/// A std-only stand-in for the fuzz target in `fuzz/`: random strings built
+/// from characters the parser treats specially must never cause a panic, and
+/// whatever parses must format and parse back unchanged.
+#[test]
+fn test_parse_duration_never_panics() {
+    const PIECES: &[&str] = &[
+        "0", "1", "9", "5", ".", ",", "_", "e", "E", "+", "-", " ", "\t", "s", "m", "h", "d", "w",
+        "ms", "us", "ns", "mo", "y", "µ", "€", "５", "min", "hours", "é",
+    ];
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+    let options = ParseOptions::new()
+        .strict(true)
+        .decimal_comma(true)
+        .scientific(true);
+
+    for _ in 0..20_000 {
+        let len = next() % 12;
+        let input: String = (0..len)
+            .map(|_| PIECES[(next() % PIECES.len() as u64) as usize])
+            .collect();
+        if let Ok(duration) = parse_duration(&input) {
+            let formatted = serde_duration::format_duration(&duration);
+            assert_eq!(parse_duration(&formatted).unwrap(), duration, "{}", input);
+        }
+        let _ = try_parse_duration(&input);
+        let _ = parse_duration_saturating(&input);
+        let _ = parse_duration_with_default(&input, Unit::Millis);
+        let _ = parse_duration_with_options(&input, &options);
+    }
+}
+
+#[test]
+fn test_parse_duration_with_mode() {
+    let canonical = vec![
+        ("30s", Duration::from_secs(30)),
+        ("1h30m", Duration::from_secs(5400)),
+        ("1500ms", Duration::from_millis(1500)),
+        ("1w1d1h1m1s1ms1us1ns", Duration::new(694_861, 1_001_001)),
+        ("2mo", Duration::from_secs(5_184_000)),
+        ("0s", Duration::ZERO),
+    ];
+    for (input, expected) in canonical {
+        for mode in [ParseMode::Strict, ParseMode::Lenient] {
+            let parsed = parse_duration_with_mode(input, mode)
+                .unwrap_or_else(|e| panic!("Failed to parse {} in {:?}: {}", input, mode, e));
+            assert_eq!(parsed, expected);
+        }
+    }
+
+    let lenient_only = vec![
+        (
+            " 30s",
+            DurationParseError::InvalidFormat("whitespace is not allowed in strict mode"),
+            0,
+        ),
+        (
+            "30 s",
+            DurationParseError::InvalidFormat("whitespace is not allowed in strict mode"),
+            2,
+        ),
+        (
+            "1h 30m",
+            DurationParseError::InvalidFormat("whitespace is not allowed in strict mode"),
+            2,
+        ),
+        (
+            "+30s",
+            DurationParseError::InvalidFormat("'+' is not allowed in strict mode"),
+            0,
+        ),
+        (
+            "1_000s",
+            DurationParseError::InvalidFormat("digit separators are not allowed in strict mode"),
+            1,
+        ),
+        (
+            "1.5h",
+            DurationParseError::InvalidFormat("fractions are not allowed in strict mode"),
+            1,
+        ),
+        (
+            "30S",
+            DurationParseError::NonCanonicalUnit("S".to_string()),
+            2,
+        ),
+        (
+            "30secs",
+            DurationParseError::NonCanonicalUnit("secs".to_string()),
+            2,
+        ),
+        (
+            "5µs",
+            DurationParseError::NonCanonicalUnit("µs".to_string()),
+            1,
+        ),
+        (
+            "1h1min",
+            DurationParseError::NonCanonicalUnit("min".to_string()),
+            3,
+        ),
+        (
+            "30m1h",
+            DurationParseError::MisorderedUnit("h".to_string()),
+            4,
+        ),
+        (
+            "1h1h",
+            DurationParseError::DuplicateUnit("h".to_string()),
+            3,
+        ),
+    ];
+    for (input, expected, position) in lenient_only {
+        assert!(
+            parse_duration_with_mode(input, ParseMode::Lenient).is_ok(),
+            "{} should parse in lenient mode",
+            input
+        );
+        let error = parse_duration_with_mode(input, ParseMode::Strict).unwrap_err();
+        assert_eq!(error.kind(), &expected, "{}", input);
+        assert_eq!(error.position(), Some(position), "{}", input);
+    }
+
+    for (input, expected) in [
+        ("", DurationParseError::EmptyInput),
+        ("-5s", DurationParseError::Negative),
+        ("5x", DurationParseError::UnknownUnit("x".to_string())),
+        ("30", DurationParseError::MissingUnit),
+    ] {
+        let error = parse_duration_with_mode(input, ParseMode::Strict).unwrap_err();
+        assert_eq!(error.kind(), &expected, "{}", input);
+    }
+    assert_eq!(ParseMode::default(), ParseMode::Lenient);
+}
+
+#[test]
+fn test_parse_duration_fraction_out_of_range() {
+    let scientific = ParseOptions::new().scientific(true);
+    let tests = vec![
+        ("1e40s", DurationParseError::Overflow),
+        ("1e400s", DurationParseError::Overflow),
+        ("1.8446744073709551616e19s", DurationParseError::Overflow),
+        ("18446744073709551616.5s", DurationParseError::Overflow),
+        ("0.5e40w", DurationParseError::Overflow),
+        ("nan s", DurationParseError::UnknownUnit("nan".to_string())),
+        ("NaNs", DurationParseError::UnknownUnit("NaNs".to_string())),
+        ("inf s", DurationParseError::UnknownUnit("inf".to_string())),
+        (
+            "infinity",
+            DurationParseError::UnknownUnit("infinity".to_string()),
+        ),
+        ("-1.5s", DurationParseError::Negative),
+    ];
+
+    for (input, expected) in tests {
+        let error = parse_duration_with_options(input, &scientific).unwrap_err();
+        assert_eq!(error.kind(), &expected, "{}", input);
+        assert!(parse_duration(input).is_err(), "{} should not parse", input);
+    }
+}
+
+#[test]
+fn test_parse_duration_in() {
+    let tests = vec![
+        ("30", Unit::Seconds, Duration::from_secs(30)),
+        ("1.5", Unit::Hours, Duration::from_secs(5400)),
+        (" +250 ", Unit::Millis, Duration::from_millis(250)),
+        ("1_000", Unit::Micros, Duration::from_millis(1)),
+        ("30s", Unit::Seconds, Duration::from_secs(30)),
+        ("30 seconds", Unit::Seconds, Duration::from_secs(30)),
+        ("5M", Unit::Minutes, Duration::from_secs(300)),
+        ("1s 1s", Unit::Seconds, Duration::from_secs(2)),
+        ("2", Unit::Months, Duration::from_secs(60 * 86_400)),
+    ];
+
+    for (input, unit, expected) in tests {
+        let parsed = parse_duration_in(input, unit)
+            .unwrap_or_else(|e| panic!("Failed to parse {}: {}", input, e));
+        assert_eq!(parsed, expected, "{}", input);
+    }
+}
+
+#[test]
+fn test_parse_duration_in_mismatched_unit() {
+    let tests = vec![
+        ("30m", Unit::Seconds, "m", 2),
+        ("30 ms", Unit::Seconds, "ms", 3),
+        ("1m30s", Unit::Seconds, "m", 1),
+        ("30s 1h", Unit::Seconds, "h", 5),
+        ("1mo", Unit::Minutes, "mo", 1),
+    ];
+
+    for (input, unit, suffix, position) in tests {
+        let error = parse_duration_in(input, unit).unwrap_err();
+        assert_eq!(
+            error.kind(),
+            &DurationParseError::UnexpectedUnit(suffix.to_string(), unit),
+            "{}",
+            input
+        );
+        assert_eq!(error.position(), Some(position), "{}", input);
+    }
+
+    let error = parse_duration_in("30m", Unit::Seconds).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid duration \"30m\": unit 'm' is not the expected unit 's' at position 2"
+    );
+}
+
+#[test]
+fn test_parse_duration_in_invalid() {
+    let tests = vec![
+        ("", DurationParseError::EmptyInput),
+        ("30x", DurationParseError::UnknownUnit("x".to_string())),
+        ("-30", DurationParseError::Negative),
+        (
+            "1.2.3",
+            DurationParseError::InvalidNumber("1.2.3".to_string()),
+        ),
+    ];
+
+    for (input, expected) in tests {
+        let error = parse_duration_in(input, Unit::Seconds).unwrap_err();
+        assert_eq!(error.kind(), &expected, "{}", input);
+    }
+}
+
+#[test]
+fn test_parse_duration_bare_zero() {
+    for input in ["0", " 0 ", "+0"] {
+        let parsed =
+            parse_duration(input).unwrap_or_else(|e| panic!("Failed to parse {}: {}", input, e));
+        assert_eq!(parsed, Duration::ZERO, "{}", input);
+    }
+    assert_eq!(try_parse_duration("0").unwrap(), Some(Duration::ZERO));
+    assert_eq!(
+        parse_duration_with_mode("0", ParseMode::Strict).unwrap(),
+        Duration::ZERO
+    );
+
+    // Only zero may go without a unit.
+    for input in ["00", "0.0", "1", "0 0", "-0"] {
+        assert!(parse_duration(input).is_err(), "{} should not parse", input);
+    }
+    assert!(parse_duration_with_mode(" 0", ParseMode::Strict).is_err());
+    assert!(parse_duration_with_mode("+0", ParseMode::Strict).is_err());
+}
+
+#[test]
+fn test_parse_duration_checked() {
+    assert_eq!(
+        parse_duration_checked("1h30m"),
+        Ok(Duration::from_secs(5400))
+    );
+    assert_eq!(parse_duration_checked("0"), Ok(Duration::ZERO));
+
+    let long = "1".repeat(300) + "s";
+    let tests = vec![
+        ("", DurationParseError::EmptyInput),
+        ("s", DurationParseError::MissingValue("s".to_string())),
+        ("1h s", DurationParseError::MissingValue("s".to_string())),
+        ("30", DurationParseError::MissingUnit),
+        ("1h30", DurationParseError::MissingUnit),
+        ("5x", DurationParseError::UnknownUnit("x".to_string())),
+        (
+            "1.2.3s",
+            DurationParseError::InvalidNumber("1.2.3".to_string()),
+        ),
+        ("18446744073709551616s", DurationParseError::Overflow),
+        ("10000000000000000000w", DurationParseError::Overflow),
+        ("18446744073709551615s 1s", DurationParseError::Overflow),
+        ("-5s", DurationParseError::Negative),
+        (
+            "_1s",
+            DurationParseError::MisplacedSeparator("_1".to_string()),
+        ),
+        (&long, DurationParseError::TooLong(256)),
+    ];
+
+    for (input, expected) in tests {
+        assert_eq!(
+            parse_duration_checked(input),
+            Err(expected.clone()),
+            "{}",
+            input
+        );
+        assert_eq!(
+            parse_duration(input).unwrap_err().kind(),
+            &expected,
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_parse_duration_min_is_minutes() {
+    // A unit is the whole run of letters, so "min" is never read as "m"
+    // followed by something else, and "ms" is never read as "m".
+    let tests = vec![
+        ("5min", Duration::from_secs(300)),
+        ("5MIN", Duration::from_secs(300)),
+        ("5 min", Duration::from_secs(300)),
+        ("1h5min", Duration::from_secs(3900)),
+        ("1h5min30s", Duration::from_secs(3930)),
+        ("1h 5 mins 30 s", Duration::from_secs(3930)),
+        ("1min1ms", Duration::from_millis(60_001)),
+        ("5min5m", Duration::from_secs(600)),
+    ];
+
+    for (input, expected) in tests {
+        let parsed =
+            parse_duration(input).unwrap_or_else(|e| panic!("Failed to parse {}: {}", input, e));
+        assert_eq!(parsed, expected, "{}", input);
+    }
+
+    let tokens: Vec<_> = DurationTokens::new("1h5min30s")
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(
+        tokens,
+        [(1, Unit::Hours), (5, Unit::Minutes), (30, Unit::Seconds)]
+    );
+
+    for (input, unit) in [("5mi", "mi"), ("5minimum", "minimum"), ("1h5mn", "mn")] {
+        assert_eq!(
+            parse_duration(input).unwrap_err().kind(),
+            &DurationParseError::UnknownUnit(unit.to_string()),
+            "{}",
+            input
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TestStruct {
+    #[serde(with = "serde_duration")]
+    duration: Duration,
+}
+
+fn round_trip(duration: Duration) -> Duration {
+    let serialized = serde_json::to_string(&TestStruct { duration })
+        .unwrap_or_else(|e| panic!("Failed to serialize {:?}: {}", duration, e));
+    let deserialized: TestStruct = serde_json::from_str(&serialized)
+        .unwrap_or_else(|e| panic!("Failed to deserialize {}: {}", serialized, e));
+    deserialized.duration
+}
+
+#[test]
+fn test_round_trip_millis() {
+    for millis in [1, 999, 1500] {
+        let duration = Duration::from_millis(millis);
+        assert_eq!(round_trip(duration), duration);
+    }
+}
+
+#[test]
+fn test_round_trip_micros() {
+    for micros in [1, 250, 1_000_001] {
+        let duration = Duration::from_micros(micros);
+        assert_eq!(round_trip(duration), duration);
+    }
+}
+
+#[test]
+fn test_round_trip_nanos() {
+    for nanos in [1, 750, 1_000_000_001] {
+        let duration = Duration::from_nanos(nanos);
+        assert_eq!(round_trip(duration), duration);
+    }
+}
+
+#[test]
+fn test_round_trip_seconds_range() {
+    for secs in (0..10_000).chain((0..1_000).map(|i| i * 3599)) {
+        let duration = Duration::from_secs(secs);
+        assert_eq!(round_trip(duration), duration);
+    }
+}
+
+#[test]
+fn test_round_trip_zero() {
+    let serialized = serde_json::to_string(&TestStruct {
+        duration: Duration::ZERO,
+    })
+    .unwrap();
+    assert_eq!(serialized, "{\"duration\":\"0s\"}");
+    assert_eq!(round_trip(Duration::ZERO), Duration::ZERO);
+}
+
+#[test]
+fn test_round_trip_max() {
+    let serialized = serde_json::to_string(&TestStruct {
+        duration: Duration::MAX,
+    })
+    .unwrap();
+    assert_eq!(
+        serialized,
+        "{\"duration\":\"18446744073709551615999999999ns\"}"
+    );
+    assert_eq!(round_trip(Duration::MAX), Duration::MAX);
+    assert_eq!(
+        round_trip(Duration::from_secs(u64::MAX)),
+        Duration::from_secs(u64::MAX)
+    );
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct EveryFormat {
+    #[serde(with = "serde_duration::compound")]
+    compound: Duration,
+    #[serde(with = "serde_duration::go")]
+    go: Duration,
+    #[serde(with = "serde_duration::iso8601")]
+    iso8601: Duration,
+    #[serde(with = "serde_duration::seconds")]
+    seconds: Duration,
+    #[serde(with = "serde_duration::millis")]
+    millis: Duration,
+    #[serde(with = "serde_duration::clock")]
+    clock: Duration,
+    #[serde(with = "serde_duration::object")]
+    object: Duration,
+}
+
+#[test]
+fn test_round_trip_max_every_format() {
+    let max = Duration::MAX;
+    let serialized = serde_json::to_string(&EveryFormat {
+        compound: max,
+        go: max,
+        iso8601: max,
+        seconds: max,
+        millis: max,
+        clock: max,
+        object: max,
+    })
+    .unwrap();
+    assert!(serialized.contains("\"go\":\"5124095576030431h0m15.999999999s\""));
+    assert!(serialized.contains("\"clock\":\"5124095576030431:00:15.999999999\""));
+
+    let deserialized: EveryFormat = serde_json::from_str(&serialized)
+        .unwrap_or_else(|e| panic!("Failed to deserialize {}: {}", serialized, e));
+    assert_eq!(deserialized.compound, max);
+    assert_eq!(deserialized.go, max);
+    assert_eq!(deserialized.iso8601, max);
+    assert_eq!(deserialized.seconds, max);
+    assert_eq!(deserialized.millis, max);
+    assert_eq!(deserialized.clock, max);
+    assert_eq!(deserialized.object, max);
+}
+
+/// A small xorshift generator, so that the property test below is
+/// reproducible without pulling in a property testing framework.
+struct XorShift(u64);
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn duration(&mut self) -> Duration {
+        // Mix magnitudes so that every unit, including weeks and nanoseconds,
+        // gets exercised rather than almost always large second counts.
+        let secs = self.next() >> (self.next() % 64);
+        let nanos = match self.next() % 4 {
+            0 => 0,
+            1 => (self.next() % 1_000) as u32 * 1_000_000,
+            2 => (self.next() % 1_000_000) as u32 * 1_000,
+            _ => (self.next() % 1_000_000_000) as u32,
+        };
+        Duration::new(secs, nanos)
+    }
+}
+
+#[test]
+fn test_round_trip_arbitrary() {
+    let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+    for _ in 0..10_000 {
+        let duration = rng.duration();
+        assert_eq!(round_trip(duration), duration);
+    }
+    for duration in [Duration::MAX, Duration::new(u64::MAX, 0)] {
+        assert_eq!(round_trip(duration), duration);
+    }
+}
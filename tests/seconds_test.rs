@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TestStruct {
+    #[serde(with = "serde_duration::seconds")]
+    duration: Duration,
+}
+
+#[test]
+fn test_serialize_seconds() {
+    let tests = vec![
+        (Duration::from_secs(90), "{\"duration\":\"90s\"}"),
+        (Duration::from_secs(3600), "{\"duration\":\"3600s\"}"),
+        (Duration::from_secs(604800), "{\"duration\":\"604800s\"}"),
+        (Duration::ZERO, "{\"duration\":\"0s\"}"),
+        (Duration::from_millis(1500), "{\"duration\":\"1.5s\"}"),
+        (Duration::from_nanos(1), "{\"duration\":\"0.000000001s\"}"),
+    ];
+
+    for (duration, expected) in tests {
+        let serialized = serde_json::to_string(&TestStruct { duration }).unwrap();
+        assert_eq!(serialized, expected);
+    }
+}
+
+#[test]
+fn test_deserialize_seconds() {
+    let tests = vec![
+        ("{\"duration\":\"90s\"}", Duration::from_secs(90)),
+        ("{\"duration\":\"1m30s\"}", Duration::from_secs(90)),
+        ("{\"duration\":\"2h\"}", Duration::from_secs(7200)),
+        ("{\"duration\":\"1.5s\"}", Duration::from_millis(1500)),
+    ];
+
+    for (json_str, expected) in tests {
+        let deserialized: TestStruct = serde_json::from_str(json_str)
+            .unwrap_or_else(|e| panic!("Failed to deserialize {}: {}", json_str, e));
+        assert_eq!(deserialized.duration, expected);
+    }
+}
+
+#[test]
+fn test_round_trip_seconds() {
+    for duration in [
+        Duration::from_secs(61),
+        Duration::new(5400, 1),
+        Duration::from_micros(250),
+    ] {
+        let serialized = serde_json::to_string(&TestStruct { duration }).unwrap();
+        let deserialized: TestStruct = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.duration, duration);
+    }
+}
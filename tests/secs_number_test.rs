@@ -0,0 +1,81 @@
+use serde::de::value::{Error, I64Deserializer, U64Deserializer};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct TestStruct {
+    #[serde(with = "serde_duration::secs_number")]
+    duration: Duration,
+}
+
+#[test]
+fn test_serialize_secs_number() {
+    let tests = vec![
+        (Duration::from_secs(90), "{\"duration\":90}"),
+        (Duration::ZERO, "{\"duration\":0}"),
+        (
+            Duration::new(u64::MAX, 0),
+            "{\"duration\":18446744073709551615}",
+        ),
+    ];
+
+    for (duration, expected) in tests {
+        let serialized = serde_json::to_string(&TestStruct { duration }).unwrap();
+        assert_eq!(serialized, expected);
+    }
+    let value = serde_json::to_value(TestStruct {
+        duration: Duration::from_secs(90),
+    })
+    .unwrap();
+    assert!(value["duration"].is_u64());
+}
+
+#[test]
+fn test_serialize_secs_number_sub_second() {
+    let result = serde_json::to_string(&TestStruct {
+        duration: Duration::from_millis(1500),
+    });
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_deserialize_secs_number() {
+    let tests = vec![
+        ("{\"duration\":90}", Duration::from_secs(90)),
+        ("{\"duration\":1.5}", Duration::from_millis(1500)),
+    ];
+
+    for (json_str, expected) in tests {
+        let deserialized: TestStruct = serde_json::from_str(json_str)
+            .unwrap_or_else(|e| panic!("Failed to deserialize {}: {}", json_str, e));
+        assert_eq!(deserialized.duration, expected);
+    }
+    for json_str in ["{\"duration\":\"90s\"}", "{\"duration\":-1}"] {
+        assert!(serde_json::from_str::<TestStruct>(json_str).is_err());
+    }
+}
+
+#[test]
+fn test_deserialize_secs_number_from_binary_style_input() {
+    // Non-self-describing formats such as bincode hand the visitor a bare
+    // integer, which these deserializers mimic.
+    let duration = serde_duration::secs_number::deserialize(U64Deserializer::<Error>::new(90));
+    assert_eq!(duration.unwrap(), Duration::from_secs(90));
+    let duration = serde_duration::secs_number::deserialize(I64Deserializer::<Error>::new(90));
+    assert_eq!(duration.unwrap(), Duration::from_secs(90));
+    assert!(serde_duration::secs_number::deserialize(I64Deserializer::<Error>::new(-1)).is_err());
+}
+
+#[test]
+fn test_round_trip_secs_number() {
+    for secs in [0, 1, 90, 86400, u64::MAX] {
+        let test_struct = TestStruct {
+            duration: Duration::from_secs(secs),
+        };
+        let value = serde_json::to_value(&test_struct).unwrap();
+        assert_eq!(
+            serde_json::from_value::<TestStruct>(value).unwrap(),
+            test_struct
+        );
+    }
+}
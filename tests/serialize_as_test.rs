@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// A domain type wrapping a duration, as downstream crates define them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Timeout(Duration);
+
+impl From<Timeout> for Duration {
+    fn from(timeout: Timeout) -> Duration {
+        timeout.0
+    }
+}
+
+impl From<Duration> for Timeout {
+    fn from(duration: Duration) -> Timeout {
+        Timeout(duration)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TestStruct {
+    #[serde(
+        serialize_with = "serde_duration::serialize_as",
+        deserialize_with = "serde_duration::deserialize_as"
+    )]
+    timeout: Timeout,
+    #[serde(deserialize_with = "serde_duration::deserialize_as")]
+    #[serde(serialize_with = "serde_duration::serialize_as")]
+    plain: Duration,
+}
+
+#[test]
+fn test_round_trip_wrapper() {
+    let value = TestStruct {
+        timeout: Timeout(Duration::from_secs(90)),
+        plain: Duration::from_millis(1500),
+    };
+    let serialized = serde_json::to_string(&value).unwrap();
+    assert_eq!(serialized, "{\"timeout\":\"90s\",\"plain\":\"1500ms\"}");
+    let deserialized: TestStruct = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized.timeout, value.timeout);
+    assert_eq!(deserialized.plain, value.plain);
+}
+
+#[test]
+fn test_deserialize_wrapper() {
+    let tests = vec![
+        ("\"1h30m\"", Timeout(Duration::from_secs(5400))),
+        ("30", Timeout(Duration::from_secs(30))),
+    ];
+
+    for (input, expected) in tests {
+        let json = format!("{{\"timeout\":{},\"plain\":\"1s\"}}", input);
+        let parsed: TestStruct = serde_json::from_str(&json)
+            .unwrap_or_else(|e| panic!("Failed to parse {}: {}", json, e));
+        assert_eq!(parsed.timeout, expected);
+    }
+
+    let error =
+        serde_json::from_str::<TestStruct>("{\"timeout\":\"5x\",\"plain\":\"1s\"}").unwrap_err();
+    assert!(error.to_string().contains("unknown unit 'x'"), "{}", error);
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::single_component_path_imports)]
+
 use serde::{Deserialize, Serialize};
 use serde_json;
 use std::time::Duration;
@@ -14,6 +16,44 @@ fn test_serialize() {
         (Duration::from_secs(1200), "{\"duration\":\"20m\"}"),
         (Duration::from_secs(5), "{\"duration\":\"5s\"}"),
         (Duration::from_secs(1200 * 60), "{\"duration\":\"20h\"}"),
+        (Duration::from_millis(500), "{\"duration\":\"500ms\"}"),
+        (Duration::from_millis(1500), "{\"duration\":\"1500ms\"}"),
+        (Duration::from_micros(250), "{\"duration\":\"250us\"}"),
+        (Duration::from_micros(1_000_250), "{\"duration\":\"1000250us\"}"),
+        (Duration::from_nanos(1), "{\"duration\":\"1ns\"}"),
+        (Duration::from_nanos(750), "{\"duration\":\"750ns\"}"),
+    ];
+
+    for (duration, expected) in tests {
+        let test_struct = TestStruct { duration };
+        let serialized = serde_json::to_string(&test_struct).unwrap();
+        assert_eq!(serialized, expected);
+    }
+}
+
+#[test]
+fn test_serialize_days_and_weeks() {
+    let tests = vec![
+        (Duration::from_secs(86400), "{\"duration\":\"1d\"}"),
+        (Duration::from_secs(7 * 86400), "{\"duration\":\"1w\"}"),
+        (Duration::from_secs(8 * 86400), "{\"duration\":\"8d\"}"),
+        (Duration::from_secs(14 * 86400), "{\"duration\":\"2w\"}"),
+    ];
+
+    for (duration, expected) in tests {
+        let test_struct = TestStruct { duration };
+        let serialized = serde_json::to_string(&test_struct).unwrap();
+        assert_eq!(serialized, expected);
+    }
+}
+
+#[test]
+fn test_serialize_inexact_falls_back_to_smaller_unit() {
+    let tests = vec![
+        (Duration::from_secs(61), "{\"duration\":\"61s\"}"),
+        (Duration::from_secs(3660), "{\"duration\":\"61m\"}"),
+        (Duration::from_secs(3661), "{\"duration\":\"3661s\"}"),
+        (Duration::from_secs(90000), "{\"duration\":\"25h\"}"),
     ];
 
     for (duration, expected) in tests {
@@ -22,3 +62,11 @@ fn test_serialize() {
         assert_eq!(serialized, expected);
     }
 }
+
+#[test]
+fn test_serialize_omits_zero_components() {
+    let duration = serde_duration::parse_duration("1h0m0s").unwrap();
+    let json = serde_json::to_string(&TestStruct { duration }).unwrap();
+    assert_eq!(json, "{\"duration\":\"1h\"}");
+    assert_eq!(serde_duration::format_duration_compound(&duration), "1h");
+}
@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+use serde_duration::{DurationParseError, SignedDuration};
+use std::time::Duration;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TestStruct {
+    offset: SignedDuration,
+}
+
+#[test]
+fn test_deserialize_signed_duration() {
+    let tests = vec![
+        (
+            "\"-30s\"",
+            SignedDuration::new(true, Duration::from_secs(30)),
+        ),
+        (
+            "\"+30s\"",
+            SignedDuration::new(false, Duration::from_secs(30)),
+        ),
+        (
+            "\"30s\"",
+            SignedDuration::new(false, Duration::from_secs(30)),
+        ),
+        (
+            " \"-1h30m\"",
+            SignedDuration::new(true, Duration::from_secs(5400)),
+        ),
+        ("\"-0s\"", SignedDuration::default()),
+        ("-90", SignedDuration::new(true, Duration::from_secs(90))),
+        ("90", SignedDuration::new(false, Duration::from_secs(90))),
+    ];
+
+    for (input, expected) in tests {
+        let json = format!("{{\"offset\":{}}}", input);
+        let parsed: TestStruct = serde_json::from_str(&json)
+            .unwrap_or_else(|e| panic!("Failed to parse {}: {}", json, e));
+        assert_eq!(parsed.offset, expected);
+    }
+}
+
+#[test]
+fn test_serialize_signed_duration() {
+    let tests = vec![
+        (SignedDuration::new(true, Duration::from_secs(30)), "-30s"),
+        (SignedDuration::new(false, Duration::from_secs(30)), "30s"),
+        (
+            SignedDuration::new(true, Duration::from_millis(1500)),
+            "-1500ms",
+        ),
+        (SignedDuration::new(true, Duration::ZERO), "0s"),
+    ];
+
+    for (offset, expected) in tests {
+        let serialized = serde_json::to_string(&TestStruct { offset }).unwrap();
+        assert_eq!(serialized, format!("{{\"offset\":\"{}\"}}", expected));
+        assert_eq!(offset.to_string(), expected);
+        let parsed: SignedDuration = expected.parse().unwrap();
+        assert_eq!(parsed, offset);
+    }
+}
+
+#[test]
+fn test_deserialize_signed_duration_invalid() {
+    let tests = vec![
+        ("\"\"", "empty duration"),
+        ("\"-\"", "empty duration"),
+        ("\"-5x\"", "unknown unit 'x'"),
+        ("\"--5s\"", "a duration may only have one sign at position 1"),
+        ("\"--30s\"", "a duration may only have one sign at position 1"),
+        ("\"-+30s\"", "a duration may only have one sign at position 1"),
+        ("\"+-30s\"", "a duration may only have one sign at position 1"),
+        ("\"- -30s\"", "a duration may only have one sign at position 2"),
+    ];
+
+    for (input, expected) in tests {
+        let json = format!("{{\"offset\":{}}}", input);
+        let error = serde_json::from_str::<TestStruct>(&json).unwrap_err();
+        assert!(
+            error.to_string().contains(expected),
+            "{:?} does not contain {:?}",
+            error.to_string(),
+            expected
+        );
+    }
+}
+
+#[test]
+fn test_parse_signed_duration_sign() {
+    let tests = vec![
+        ("-30s", SignedDuration::new(true, Duration::from_secs(30))),
+        ("+30s", SignedDuration::new(false, Duration::from_secs(30))),
+        (" - 30s", SignedDuration::new(true, Duration::from_secs(30))),
+    ];
+    for (input, expected) in tests {
+        let parsed: SignedDuration = input
+            .parse()
+            .unwrap_or_else(|e| panic!("Failed to parse {}: {}", input, e));
+        assert_eq!(parsed, expected, "{}", input);
+    }
+
+    for input in ["--30s", "-+30s", "++30s"] {
+        let error = input.parse::<SignedDuration>().unwrap_err();
+        assert_eq!(error.kind(), &DurationParseError::RepeatedSign, "{}", input);
+    }
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "time")]
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TestStruct {
+    #[serde(with = "serde_duration::time")]
+    duration: time::Duration,
+}
+
+#[test]
+fn test_serialize_time() {
+    let tests = vec![
+        (time::Duration::seconds(30), "{\"duration\":\"30s\"}"),
+        (time::Duration::seconds(-30), "{\"duration\":\"-30s\"}"),
+        (time::Duration::hours(-2), "{\"duration\":\"-2h\"}"),
+        (
+            time::Duration::nanoseconds(-1500),
+            "{\"duration\":\"-1500ns\"}",
+        ),
+        (time::Duration::ZERO, "{\"duration\":\"0s\"}"),
+    ];
+
+    for (duration, expected) in tests {
+        let test_struct = TestStruct { duration };
+        let serialized = serde_json::to_string(&test_struct).unwrap();
+        assert_eq!(serialized, expected);
+    }
+}
+
+#[test]
+fn test_deserialize_time() {
+    let tests = vec![
+        ("{\"duration\":\"30s\"}", time::Duration::seconds(30)),
+        ("{\"duration\":\"-30s\"}", time::Duration::seconds(-30)),
+        (
+            "{\"duration\":\"-1.5ms\"}",
+            time::Duration::microseconds(-1500),
+        ),
+        ("{\"duration\":-30}", time::Duration::seconds(-30)),
+    ];
+
+    for (json_str, expected) in tests {
+        let deserialized: TestStruct = serde_json::from_str(json_str)
+            .unwrap_or_else(|e| panic!("Failed to deserialize JSON: {}", e));
+        assert_eq!(deserialized.duration, expected);
+    }
+}
+
+#[test]
+fn test_round_trip_time() {
+    for nanos in [-1_000_000_001, -1, 0, 1, 999, 90_000_000_000] {
+        let duration = time::Duration::nanoseconds(nanos);
+        let serialized = serde_json::to_string(&TestStruct { duration }).unwrap();
+        let deserialized: TestStruct = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.duration, duration);
+    }
+}
+
+#[test]
+fn test_deserialize_time_repeated_sign() {
+    for input in ["--30s", "-+30s", "+-30s", "++30s"] {
+        let json_str = format!("{{\"duration\":\"{}\"}}", input);
+        let error = serde_json::from_str::<TestStruct>(&json_str).unwrap_err();
+        assert!(
+            error.to_string().contains("a duration may only have one sign"),
+            "{:?} does not contain {:?}",
+            error.to_string(),
+            "a duration may only have one sign"
+        );
+    }
+
+    let json_str = "{\"duration\":\"+30s\"}";
+    let deserialized: TestStruct = serde_json::from_str(json_str)
+        .unwrap_or_else(|e| panic!("Failed to deserialize JSON: {}", e));
+    assert_eq!(deserialized.duration, time::Duration::seconds(30));
+}
@@ -0,0 +1,94 @@
+use serde_duration::{parse_duration, DurationParseError, DurationTokens, Unit};
+
+#[test]
+fn test_duration_tokens() {
+    let tests = vec![
+        (
+            "1h30m15s",
+            vec![(1, Unit::Hours), (30, Unit::Minutes), (15, Unit::Seconds)],
+        ),
+        ("30s", vec![(30, Unit::Seconds)]),
+        (" +1 hour 2 hrs ", vec![(1, Unit::Hours), (2, Unit::Hours)]),
+        ("30s1h", vec![(30, Unit::Seconds), (1, Unit::Hours)]),
+        ("1_000ms 5us", vec![(1000, Unit::Millis), (5, Unit::Micros)]),
+        ("1mo1y", vec![(1, Unit::Months), (1, Unit::Years)]),
+        (
+            "18446744073709551615ns",
+            vec![(u64::MAX.into(), Unit::Nanos)],
+        ),
+        (
+            "20000000000000000000ns",
+            vec![(20_000_000_000_000_000_000, Unit::Nanos)],
+        ),
+        ("0", vec![]),
+        (" +0 ", vec![]),
+        ("0s", vec![(0, Unit::Seconds)]),
+    ];
+
+    for (input, expected) in tests {
+        let tokens: Vec<(u128, Unit)> = DurationTokens::new(input)
+            .collect::<Result<_, _>>()
+            .unwrap_or_else(|e| panic!("Failed to parse {}: {}", input, e));
+        assert_eq!(tokens, expected, "{}", input);
+    }
+}
+
+#[test]
+fn test_duration_tokens_errors() {
+    let tests = vec![
+        ("", DurationParseError::EmptyInput),
+        ("-5s", DurationParseError::Negative),
+        ("30", DurationParseError::MissingUnit),
+        ("5x", DurationParseError::UnknownUnit("x".to_string())),
+        ("1.5h", DurationParseError::InvalidNumber("1.5".to_string())),
+        ("1.0h", DurationParseError::InvalidNumber("1.0".to_string())),
+        ("1,0h", DurationParseError::InvalidNumber("1,0".to_string())),
+        (
+            "_1s",
+            DurationParseError::MisplacedSeparator("_1".to_string()),
+        ),
+        ("18446744073709551616s", DurationParseError::Overflow),
+    ];
+
+    for (input, expected) in tests {
+        let mut tokens = DurationTokens::new(input);
+        let error = tokens.next().unwrap().unwrap_err();
+        assert_eq!(error.kind(), &expected, "{}", input);
+        assert!(tokens.next().is_none());
+    }
+}
+
+#[test]
+fn test_duration_tokens_stop_after_error() {
+    let mut tokens = DurationTokens::new("1h 5x 30m");
+    assert_eq!(tokens.next().unwrap().unwrap(), (1, Unit::Hours));
+    let error = tokens.next().unwrap().unwrap_err();
+    assert_eq!(
+        error.kind(),
+        &DurationParseError::UnknownUnit("x".to_string())
+    );
+    assert_eq!(error.position(), Some(4));
+    assert!(tokens.next().is_none());
+}
+
+#[test]
+fn test_duration_tokens_match_parse_duration() {
+    for input in [
+        "0",
+        "1h30m",
+        "20000000000000000000ns",
+        "1mo 1y",
+        "18446744073709551615s",
+    ] {
+        let total = DurationTokens::new(input)
+            .map(|token| token.map(|(value, unit)| unit.as_duration().as_nanos() * value))
+            .sum::<Result<u128, _>>()
+            .unwrap_or_else(|e| panic!("Failed to parse {}: {}", input, e));
+        assert_eq!(
+            total,
+            parse_duration(input).unwrap().as_nanos(),
+            "{}",
+            input
+        );
+    }
+}
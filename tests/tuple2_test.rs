@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TestStruct {
+    #[serde(with = "serde_duration::tuple2")]
+    backoff: (Duration, Duration),
+}
+
+#[test]
+fn test_round_trip_tuple2() {
+    let backoff = (Duration::from_secs(1), Duration::from_secs(30));
+    let serialized = serde_json::to_string(&TestStruct { backoff }).unwrap();
+    assert_eq!(serialized, "{\"backoff\":[\"1s\",\"30s\"]}");
+    let deserialized: TestStruct = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized.backoff, backoff);
+}
+
+#[test]
+fn test_deserialize_tuple2() {
+    let tests = vec![
+        (
+            "{\"backoff\":[\"100ms\",\"1m\"]}",
+            (Duration::from_millis(100), Duration::from_secs(60)),
+        ),
+        (
+            "{\"backoff\":[1,\"1h30m\"]}",
+            (Duration::from_secs(1), Duration::from_secs(5400)),
+        ),
+    ];
+
+    for (input, expected) in tests {
+        let parsed: TestStruct = serde_json::from_str(input)
+            .unwrap_or_else(|e| panic!("Failed to parse {}: {}", input, e));
+        assert_eq!(parsed.backoff, expected);
+    }
+}
+
+#[test]
+fn test_deserialize_tuple2_invalid() {
+    let tests = vec![
+        ("{\"backoff\":[\"1s\"]}", "invalid length 1"),
+        (
+            "{\"backoff\":[\"1s\",\"2s\",\"3s\"]}",
+            "trailing characters",
+        ),
+        ("{\"backoff\":[\"1s\",\"5x\"]}", "unknown unit 'x'"),
+        ("{\"backoff\":\"1s\"}", "invalid type"),
+    ];
+
+    for (input, expected) in tests {
+        let error = serde_json::from_str::<TestStruct>(input).unwrap_err();
+        assert!(
+            error.to_string().contains(expected),
+            "{:?} does not contain {:?}",
+            error.to_string(),
+            expected
+        );
+    }
+}
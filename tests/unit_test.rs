@@ -0,0 +1,120 @@
+use serde_duration::{format_duration_as, parse_duration, DurationFormat, Unit};
+use std::time::Duration;
+
+const ALL_UNITS: [(Unit, &str); 10] = [
+    (Unit::Nanos, "ns"),
+    (Unit::Micros, "us"),
+    (Unit::Millis, "ms"),
+    (Unit::Seconds, "s"),
+    (Unit::Minutes, "m"),
+    (Unit::Hours, "h"),
+    (Unit::Days, "d"),
+    (Unit::Weeks, "w"),
+    (Unit::Months, "mo"),
+    (Unit::Years, "y"),
+];
+
+#[test]
+fn test_unit_from_str() {
+    for (unit, suffix) in ALL_UNITS {
+        assert_eq!(suffix.parse::<Unit>().unwrap(), unit);
+        assert_eq!(suffix.to_uppercase().parse::<Unit>().unwrap(), unit);
+    }
+    let tests = vec![
+        ("µs", Unit::Micros),
+        ("seconds", Unit::Seconds),
+        ("MIN", Unit::Minutes),
+        ("hrs", Unit::Hours),
+    ];
+    for (input, expected) in tests {
+        assert_eq!(input.parse::<Unit>().unwrap(), expected);
+    }
+}
+
+#[test]
+fn test_unit_from_str_case_is_unambiguous() {
+    let tests = vec![
+        ("m", Unit::Minutes),
+        ("M", Unit::Minutes),
+        ("mo", Unit::Months),
+        ("MO", Unit::Months),
+        ("Mo", Unit::Months),
+        ("ms", Unit::Millis),
+        ("MS", Unit::Millis),
+        ("Y", Unit::Years),
+    ];
+    for (input, expected) in tests {
+        assert_eq!(input.parse::<Unit>().unwrap(), expected, "{}", input);
+    }
+
+    assert_eq!(parse_duration("5M").unwrap(), Duration::from_secs(300));
+    assert_eq!(
+        parse_duration("1mo").unwrap(),
+        Duration::from_secs(30 * 86_400)
+    );
+    assert_eq!(
+        parse_duration("1MO").unwrap(),
+        Duration::from_secs(30 * 86_400)
+    );
+}
+
+#[test]
+fn test_unit_from_str_unknown() {
+    for input in ["", "x", "5s", " s", "sec s"] {
+        let error = input.parse::<Unit>().unwrap_err();
+        assert_eq!(error.to_string(), format!("unknown unit '{}'", input));
+    }
+}
+
+#[test]
+fn test_unit_display_round_trip() {
+    for (unit, suffix) in ALL_UNITS {
+        assert_eq!(unit.to_string(), suffix);
+        assert_eq!(unit.to_string().parse::<Unit>().unwrap(), unit);
+    }
+}
+
+#[test]
+fn test_unit_lengths() {
+    let tests = vec![
+        (Unit::Nanos, Duration::from_nanos(1), None),
+        (Unit::Millis, Duration::from_millis(1), None),
+        (Unit::Seconds, Duration::from_secs(1), Some(1)),
+        (Unit::Hours, Duration::from_secs(3600), Some(3600)),
+        (Unit::Weeks, Duration::from_secs(604_800), Some(604_800)),
+        (
+            Unit::Years,
+            Duration::from_secs(31_536_000),
+            Some(31_536_000),
+        ),
+    ];
+
+    for (unit, duration, multiplier) in tests {
+        assert_eq!(unit.as_duration(), duration);
+        assert_eq!(unit.as_secs_multiplier(), multiplier);
+    }
+}
+
+#[test]
+fn test_unit_parse_format_round_trip() {
+    for (unit, suffix) in ALL_UNITS {
+        let duration = unit.as_duration() * 3;
+        let formatted = format_duration_as(&duration, unit, false).unwrap();
+        assert_eq!(formatted, format!("3{}", suffix));
+        assert_eq!(
+            parse_duration(&formatted).unwrap(),
+            duration,
+            "{}",
+            formatted
+        );
+
+        let formatted = DurationFormat::new().largest_unit(unit).format(&duration);
+        assert_eq!(formatted, format!("3{}", suffix));
+        assert_eq!(
+            parse_duration(&formatted).unwrap(),
+            duration,
+            "{}",
+            formatted
+        );
+    }
+}
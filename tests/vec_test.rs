@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TestStruct {
+    #[serde(with = "serde_duration::vec")]
+    durations: Vec<Duration>,
+}
+
+#[test]
+fn test_round_trip_vec() {
+    let durations = vec![
+        Duration::from_secs(1),
+        Duration::from_secs(5),
+        Duration::from_secs(30),
+    ];
+    let serialized = serde_json::to_string(&TestStruct {
+        durations: durations.clone(),
+    })
+    .unwrap();
+    assert_eq!(serialized, "{\"durations\":[\"1s\",\"5s\",\"30s\"]}");
+
+    let deserialized: TestStruct = serde_json::from_str(&serialized)
+        .unwrap_or_else(|e| panic!("Failed to deserialize JSON: {}", e));
+    assert_eq!(deserialized.durations, durations);
+}
+
+#[test]
+fn test_deserialize_vec_invalid_element() {
+    let error = serde_json::from_str::<TestStruct>("{\"durations\":[\"1s\",\"5x\",\"30s\"]}")
+        .unwrap_err();
+    assert!(error.to_string().contains("at index 1"), "{}", error);
+}
@@ -0,0 +1,131 @@
+use serde::{Deserialize, Serialize};
+use serde_duration::serde_duration_with;
+use std::time::Duration;
+
+serde_duration_with!(mod as_millis, unit = Millis);
+serde_duration_with!(pub mod bare_seconds, default_unit = Seconds, compound = true,);
+serde_duration_with!(mod plain);
+
+#[derive(Debug, Serialize, Deserialize)]
+struct TestStruct {
+    #[serde(with = "as_millis")]
+    latency: Duration,
+    #[serde(with = "bare_seconds")]
+    timeout: Duration,
+    #[serde(with = "plain")]
+    interval: Duration,
+}
+
+#[test]
+fn test_serialize_with_configured_modules() {
+    let test_struct = TestStruct {
+        latency: Duration::from_secs(2),
+        timeout: Duration::from_secs(90),
+        interval: Duration::from_secs(120),
+    };
+    let serialized = serde_json::to_string(&test_struct).unwrap();
+    assert_eq!(
+        serialized,
+        "{\"latency\":\"2000ms\",\"timeout\":\"1m30s\",\"interval\":\"2m\"}"
+    );
+}
+
+#[test]
+fn test_deserialize_with_configured_modules() {
+    let json_str = "{\"latency\":\"1.5s\",\"timeout\":\"30\",\"interval\":\"1h\"}";
+    let deserialized: TestStruct = serde_json::from_str(json_str)
+        .unwrap_or_else(|e| panic!("Failed to deserialize JSON: {}", e));
+    assert_eq!(deserialized.latency, Duration::from_millis(1500));
+    assert_eq!(deserialized.timeout, Duration::from_secs(30));
+    assert_eq!(deserialized.interval, Duration::from_secs(3600));
+}
+
+#[test]
+fn test_deserialize_without_default_unit() {
+    // Bare numbers are seconds unless a default unit is configured.
+    let json_str = "{\"latency\":\"30\",\"timeout\":\"30\",\"interval\":\"1h\"}";
+    let deserialized: TestStruct = serde_json::from_str(json_str)
+        .unwrap_or_else(|e| panic!("Failed to deserialize JSON: {}", e));
+    assert_eq!(deserialized.latency, Duration::from_secs(30));
+}
+
+#[test]
+fn test_serialize_inexact_unit() {
+    let test_struct = TestStruct {
+        latency: Duration::from_micros(1500),
+        timeout: Duration::ZERO,
+        interval: Duration::ZERO,
+    };
+    assert!(serde_json::to_string(&test_struct).is_err());
+}
+
+serde_duration_with!(mod infinite_millis, unit = Millis, infinite = true);
+
+#[derive(Debug, Serialize, Deserialize)]
+struct InfiniteStruct {
+    #[serde(with = "infinite_millis")]
+    timeout: Duration,
+}
+
+#[test]
+fn test_infinite_option() {
+    let serialized = serde_json::to_string(&InfiniteStruct {
+        timeout: Duration::MAX,
+    })
+    .unwrap();
+    assert_eq!(serialized, "{\"timeout\":\"inf\"}");
+    let deserialized: InfiniteStruct = serde_json::from_str("{\"timeout\":\"Infinite\"}").unwrap();
+    assert_eq!(deserialized.timeout, Duration::MAX);
+}
+
+serde_duration_with!(
+    mod timeout_policy,
+    min = Duration::from_secs(1),
+    max = Duration::from_secs(3600)
+);
+serde_duration_with!(mod at_least_a_millisecond, min = std::time::Duration::from_millis(1));
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BoundedStruct {
+    #[serde(with = "timeout_policy")]
+    timeout: Duration,
+}
+
+#[test]
+fn test_bounds_in_range() {
+    for (input, expected) in [
+        ("1s", Duration::from_secs(1)),
+        ("30m", Duration::from_secs(1800)),
+        ("1h", Duration::from_secs(3600)),
+    ] {
+        let json_str = format!("{{\"timeout\":\"{}\"}}", input);
+        let deserialized: BoundedStruct = serde_json::from_str(&json_str)
+            .unwrap_or_else(|e| panic!("Failed to deserialize {}: {}", json_str, e));
+        assert_eq!(deserialized.timeout, expected);
+    }
+}
+
+#[test]
+fn test_bounds_exceeded() {
+    let tests = vec![
+        ("500ms", "duration 500ms is below the minimum of 1s"),
+        ("0s", "duration 0s is below the minimum of 1s"),
+        ("61m", "duration 61m is above the maximum of 1h"),
+        ("2h", "duration 2h is above the maximum of 1h"),
+    ];
+
+    for (input, expected) in tests {
+        let json_str = format!("{{\"timeout\":\"{}\"}}", input);
+        let error = serde_json::from_str::<BoundedStruct>(&json_str).unwrap_err();
+        assert!(
+            error.to_string().contains(expected),
+            "{:?} does not contain {:?}",
+            error.to_string(),
+            expected
+        );
+    }
+
+    let result =
+        at_least_a_millisecond::deserialize(&mut serde_json::Deserializer::from_str("\"1us\""));
+    assert!(result.is_err());
+}